@@ -66,8 +66,11 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     let mut columns_trait: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_select_as: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_save_as: Punctuated<_, Comma> = Punctuated::new();
+    let mut columns_auto_timestamp: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_keys: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_key_types: Punctuated<_, Comma> = Punctuated::new();
+    let mut version_columns: Punctuated<_, Comma> = Punctuated::new();
+    let mut soft_delete_columns: Punctuated<_, Comma> = Punctuated::new();
     let mut auto_increment = true;
     if table_iden {
         if let Some(table_name) = table_name {
@@ -101,6 +104,10 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     let mut indexed = false;
                     let mut ignore = false;
                     let mut unique = false;
+                    let mut version = false;
+                    let mut soft_delete = false;
+                    let mut created_at = false;
+                    let mut updated_at = false;
                     let mut sql_type = None;
                     let mut column_name = if original_field_name
                         != original_field_name.to_upper_camel_case().to_snake_case()
@@ -112,7 +119,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     };
                     let mut enum_name = None;
                     let mut is_primary_key = false;
-                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique)]
+                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique, version, soft_delete, created_at, updated_at)]
                     for attr in field.attrs.iter() {
                         if let Some(ident) = attr.path.get_ident() {
                             if ident != "sea_orm" {
@@ -212,6 +219,14 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                                 indexed = true;
                                             } else if name == "unique" {
                                                 unique = true;
+                                            } else if name == "version" {
+                                                version = true;
+                                            } else if name == "soft_delete" {
+                                                soft_delete = true;
+                                            } else if name == "created_at" {
+                                                created_at = true;
+                                            } else if name == "updated_at" {
+                                                updated_at = true;
                                             }
                                         }
                                     }
@@ -264,6 +279,15 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                         });
                     }
 
+                    if created_at || updated_at {
+                        let field_type = &field.ty;
+                        columns_auto_timestamp.push(quote! {
+                            Self::#field_name => Some(
+                                <#field_type as sea_orm::CurrentTimestamp>::current_timestamp().into()
+                            )
+                        });
+                    }
+
                     let field_type = &field.ty;
                     let field_type = quote! { #field_type }
                         .to_string() //E.g.: "Option < String >"
@@ -338,6 +362,20 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     if unique {
                         match_row = quote! { #match_row.unique() };
                     }
+                    if version {
+                        match_row = quote! { #match_row.version() };
+                        version_columns.push(field_name.clone());
+                    }
+                    if soft_delete {
+                        match_row = quote! { #match_row.soft_delete() };
+                        soft_delete_columns.push(field_name.clone());
+                    }
+                    if created_at {
+                        match_row = quote! { #match_row.created_at() };
+                    }
+                    if updated_at {
+                        match_row = quote! { #match_row.updated_at() };
+                    }
                     if let Some(default_value) = default_value {
                         match_row = quote! { #match_row.default_value(#default_value) };
                     }
@@ -357,6 +395,27 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     if !columns_save_as.is_empty() {
         columns_save_as.push_punct(Comma::default());
     }
+    if !columns_auto_timestamp.is_empty() {
+        columns_auto_timestamp.push_punct(Comma::default());
+    }
+
+    if primary_keys.len() > 6 {
+        return Ok(quote_spanned! {
+            primary_keys.span() => compile_error!("Primary key cannot have more than 6 columns");
+        });
+    }
+
+    if version_columns.len() > 1 {
+        return Ok(quote_spanned! {
+            version_columns.span() => compile_error!("Entity cannot have more than one version column");
+        });
+    }
+
+    if soft_delete_columns.len() > 1 {
+        return Ok(quote_spanned! {
+            soft_delete_columns.span() => compile_error!("Entity cannot have more than one soft delete column");
+        });
+    }
 
     let primary_key = {
         let auto_increment = auto_increment && primary_keys.len() == 1;
@@ -414,6 +473,13 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     _ => sea_orm::prelude::ColumnTrait::save_enum_as(self, val),
                 }
             }
+
+            fn auto_timestamp(&self) -> Option<sea_orm::sea_query::Value> {
+                match self {
+                    #columns_auto_timestamp
+                    _ => None,
+                }
+            }
         }
 
         #entity_def
@@ -1,6 +1,102 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
-use syn::{ext::IdentExt, Data, DataStruct, Field, Fields};
+use syn::{
+    ext::IdentExt, punctuated::Punctuated, token::Comma, Data, DataStruct, Field, Fields,
+    GenericArgument, Lit, Meta, PathArguments, Type,
+};
+
+/// How a field is read from the [QueryResult](sea_orm::QueryResult)
+enum FieldKind {
+    /// From the column of the given name
+    Column(String),
+    /// Not read, but set to its default value
+    Skip,
+    /// From the columns of a nested struct, with the given prefix.
+    /// The nested struct is `None` if it cannot be read, if the field is an `Option`.
+    Nested { prefix: String, optional: bool },
+}
+
+/// The `#[sea_orm(...)]` attributes of a field, ignoring the ones of DerivePartialModel
+fn field_kind(field: &Field) -> syn::Result<FieldKind> {
+    let name = field
+        .ident
+        .as_ref()
+        .expect("named field")
+        .unraw()
+        .to_string();
+    let mut alias = None;
+    let mut skip = false;
+    let mut nested = false;
+    let mut prefix = None;
+    for attr in field.attrs.iter() {
+        if !attr.path.is_ident("sea_orm") {
+            continue;
+        }
+        let list = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?;
+        for meta in list.iter() {
+            match meta {
+                Meta::Path(path) if path.is_ident("skip") => skip = true,
+                Meta::Path(path) if path.is_ident("nested") => nested = true,
+                Meta::NameValue(nv) if nv.path.is_ident("alias") || nv.path.is_ident("prefix") => {
+                    let value = match &nv.lit {
+                        Lit::Str(lit) => lit.value(),
+                        lit => return Err(syn::Error::new_spanned(lit, "expected a string")),
+                    };
+                    if nv.path.is_ident("alias") {
+                        alias = Some(value);
+                    } else {
+                        prefix = Some(value);
+                    }
+                }
+                // selected by DerivePartialModel, which is derived along with FromQueryResult
+                Meta::NameValue(nv)
+                    if ["entity", "from_col", "from_expr"]
+                        .iter()
+                        .any(|key| nv.path.is_ident(key)) => {}
+                meta => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "unknown attribute, expected one of `alias`, `skip`, `nested` or `prefix`",
+                    ))
+                }
+            }
+        }
+    }
+
+    if skip {
+        Ok(FieldKind::Skip)
+    } else if nested {
+        Ok(FieldKind::Nested {
+            prefix: prefix.unwrap_or_default(),
+            optional: option_inner(&field.ty).is_some(),
+        })
+    } else if prefix.is_some() {
+        Err(syn::Error::new_spanned(
+            field,
+            "`prefix` can only be specified for a `nested` field",
+        ))
+    } else {
+        Ok(FieldKind::Column(alias.unwrap_or(name)))
+    }
+}
+
+/// The type wrapped in an `Option`, if it is one
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(type_path) => type_path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
 
 /// Method to derive a [QueryResult](sea_orm::QueryResult)
 pub fn expand_derive_from_query_result(ident: Ident, data: Data) -> syn::Result<TokenStream> {
@@ -16,25 +112,43 @@ pub fn expand_derive_from_query_result(ident: Ident, data: Data) -> syn::Result<
         }
     };
 
-    let field: Vec<Ident> = fields
-        .into_iter()
-        .map(|Field { ident, .. }| format_ident!("{}", ident.unwrap().to_string()))
-        .collect();
-
-    let name: Vec<TokenStream> = field
-        .iter()
-        .map(|f| {
-            let s = f.unraw().to_string();
-            quote! { #s }
-        })
-        .collect();
+    let mut field = Vec::with_capacity(fields.len());
+    let mut value = Vec::with_capacity(fields.len());
+    for f in fields.iter() {
+        field.push(format_ident!(
+            "{}",
+            f.ident.as_ref().expect("named field").to_string()
+        ));
+        value.push(match field_kind(f)? {
+            FieldKind::Column(name) => quote! { row.try_get(pre, #name)? },
+            FieldKind::Skip => quote! { std::default::Default::default() },
+            FieldKind::Nested {
+                prefix,
+                optional: false,
+            } => {
+                let ty = &f.ty;
+                quote! {
+                    <#ty as sea_orm::FromQueryResult>::from_query_result(row, &format!("{}{}", pre, #prefix))?
+                }
+            }
+            FieldKind::Nested {
+                prefix,
+                optional: true,
+            } => {
+                let ty = option_inner(&f.ty).expect("optional field");
+                quote! {
+                    <#ty as sea_orm::FromQueryResult>::from_query_result_optional(row, &format!("{}{}", pre, #prefix))?
+                }
+            }
+        });
+    }
 
     Ok(quote!(
         #[automatically_derived]
         impl sea_orm::FromQueryResult for #ident {
             fn from_query_result(row: &sea_orm::QueryResult, pre: &str) -> std::result::Result<Self, sea_orm::DbErr> {
                 Ok(Self {
-                    #(#field: row.try_get(pre, #name)?),*
+                    #(#field: #value),*
                 })
             }
         }
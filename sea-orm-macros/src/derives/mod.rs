@@ -8,6 +8,7 @@ mod from_query_result;
 mod into_active_model;
 mod migration;
 mod model;
+mod partial_model;
 mod primary_key;
 mod relation;
 mod try_getable_from_json;
@@ -22,6 +23,7 @@ pub use from_query_result::*;
 pub use into_active_model::*;
 pub use migration::*;
 pub use model::*;
+pub use partial_model::*;
 pub use primary_key::*;
 pub use relation::*;
 pub use try_getable_from_json::*;
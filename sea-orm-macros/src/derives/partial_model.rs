@@ -0,0 +1,206 @@
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    ext::IdentExt, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Expr,
+    Fields, Lit, Meta, Type,
+};
+
+enum Error {
+    InputNotStruct,
+    EntityNotSpecified(Span),
+    Syn(syn::Error),
+}
+
+/// The column a field of the partial model is selected from
+enum ColumnAs {
+    /// A column of the entity, or of the given joined entity
+    Col {
+        entity: Option<Type>,
+        col: syn::Ident,
+        alias: String,
+    },
+    /// A custom expression
+    Expr { expr: Expr, alias: String },
+}
+
+struct DerivePartialModel {
+    entity: Option<Type>,
+    ident: syn::Ident,
+    fields: Vec<ColumnAs>,
+}
+
+/// The `key = "value"` pairs and the `key` flags of the `#[sea_orm(...)]` attributes,
+/// ignoring the other attributes
+fn sea_orm_attrs(
+    attrs: &[syn::Attribute],
+    expected: &str,
+) -> Result<Vec<(syn::Ident, Option<syn::LitStr>)>, Error> {
+    let mut pairs = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident("sea_orm") {
+            continue;
+        }
+        let list = attr
+            .parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+            .map_err(Error::Syn)?;
+        for meta in list {
+            let (name, lit) = match &meta {
+                Meta::NameValue(nv) => match &nv.lit {
+                    Lit::Str(lit) => (nv.path.get_ident(), Some(lit.clone())),
+                    lit => {
+                        return Err(Error::Syn(syn::Error::new_spanned(
+                            lit,
+                            "expected a string",
+                        )))
+                    }
+                },
+                Meta::Path(path) => (path.get_ident(), None),
+                Meta::List(_) => (None, None),
+            };
+            match name {
+                Some(name) => pairs.push((name.clone(), lit)),
+                None => return Err(unknown_attr(&meta, expected)),
+            }
+        }
+    }
+    Ok(pairs)
+}
+
+const STRUCT_ATTRS: &str = "`entity`";
+const FIELD_ATTRS: &str = "one of `entity`, `from_col`, `from_expr`, `alias` or `skip`";
+
+fn unknown_attr<T: quote::ToTokens>(tokens: T, expected: &str) -> Error {
+    Error::Syn(syn::Error::new_spanned(
+        tokens,
+        format!("unknown attribute, expected {expected}"),
+    ))
+}
+
+impl DerivePartialModel {
+    fn new(input: DeriveInput) -> Result<Self, Error> {
+        let fields = match input.data {
+            Data::Struct(DataStruct {
+                fields: Fields::Named(named),
+                ..
+            }) => named.named,
+            _ => return Err(Error::InputNotStruct),
+        };
+
+        let mut entity = None;
+        for (name, lit) in sea_orm_attrs(&input.attrs, STRUCT_ATTRS)? {
+            match (name.to_string().as_str(), lit) {
+                ("entity", Some(lit)) => entity = Some(lit.parse::<Type>().map_err(Error::Syn)?),
+                _ => return Err(unknown_attr(name, STRUCT_ATTRS)),
+            }
+        }
+
+        let mut column_as_list = Vec::with_capacity(fields.len());
+        for field in fields {
+            let field_ident = field.ident.as_ref().expect("named field");
+            let mut alias = field_ident.unraw().to_string();
+            let mut from_entity = None;
+            let mut from_col = None;
+            let mut from_expr = None;
+            let mut skip = false;
+            for (name, lit) in sea_orm_attrs(&field.attrs, FIELD_ATTRS)? {
+                match (name.to_string().as_str(), lit) {
+                    ("entity", Some(lit)) => {
+                        from_entity = Some(lit.parse::<Type>().map_err(Error::Syn)?)
+                    }
+                    ("from_col", Some(lit)) => from_col = Some(lit.value()),
+                    ("from_expr", Some(lit)) => {
+                        from_expr = Some(lit.parse::<Expr>().map_err(Error::Syn)?)
+                    }
+                    // the column read by `FromQueryResult`
+                    ("alias", Some(lit)) => alias = lit.value(),
+                    // the field isn't read by `FromQueryResult`
+                    ("skip", None) => skip = true,
+                    ("nested", None) | ("prefix", Some(_)) => {
+                        return Err(Error::Syn(syn::Error::new_spanned(
+                            name,
+                            "a `nested` struct cannot be selected by DerivePartialModel",
+                        )))
+                    }
+                    _ => return Err(unknown_attr(name, FIELD_ATTRS)),
+                }
+            }
+            if skip {
+                continue;
+            }
+
+            let column_as = match from_expr {
+                Some(expr) => ColumnAs::Expr { expr, alias },
+                None => {
+                    if entity.is_none() && from_entity.is_none() {
+                        return Err(Error::EntityNotSpecified(field_ident.span()));
+                    }
+                    let col = from_col.unwrap_or_else(|| alias.clone());
+                    ColumnAs::Col {
+                        entity: from_entity,
+                        col: format_ident!("{}", col.to_upper_camel_case()),
+                        alias,
+                    }
+                }
+            };
+            column_as_list.push(column_as);
+        }
+
+        Ok(Self {
+            entity,
+            ident: input.ident,
+            fields: column_as_list,
+        })
+    }
+
+    fn expand(&self) -> syn::Result<TokenStream> {
+        Ok(self.impl_partial_model_trait())
+    }
+
+    fn impl_partial_model_trait(&self) -> TokenStream {
+        let ident = &self.ident;
+
+        let select_col_code_gen = self.fields.iter().map(|column_as| match column_as {
+            ColumnAs::Col { entity, col, alias } => {
+                let entity = entity.as_ref().or(self.entity.as_ref());
+                quote!(
+                    let col = <#entity as sea_orm::EntityTrait>::Column::#col;
+                    let select = sea_orm::QuerySelect::column_as(
+                        select,
+                        sea_orm::ColumnTrait::select_as(&col, sea_orm::ColumnTrait::into_expr(col)),
+                        #alias,
+                    );
+                )
+            }
+            ColumnAs::Expr { expr, alias } => quote!(
+                let select = sea_orm::QuerySelect::column_as(select, #expr, #alias);
+            ),
+        });
+
+        quote!(
+            #[automatically_derived]
+            impl sea_orm::PartialModelTrait for #ident {
+                fn select_cols<S: sea_orm::QuerySelect>(select: S) -> S {
+                    #(#select_col_code_gen)*
+                    select
+                }
+            }
+        )
+    }
+}
+
+/// Method to derive a [PartialModelTrait](sea_orm::PartialModelTrait)
+pub fn expand_derive_partial_model(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident_span = input.ident.span();
+
+    match DerivePartialModel::new(input) {
+        Ok(partial_model) => partial_model.expand(),
+        Err(Error::InputNotStruct) => Ok(quote_spanned! {
+            ident_span => compile_error!("you can only derive DerivePartialModel on structs");
+        }),
+        Err(Error::EntityNotSpecified(span)) => Ok(quote_spanned! {
+            span => compile_error!("you need to specify the `entity` of the column, or select it `from_expr`");
+        }),
+        Err(Error::Syn(err)) => Err(err),
+    }
+}
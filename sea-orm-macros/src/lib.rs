@@ -129,6 +129,37 @@ pub fn derive_entity(input: TokenStream) -> TokenStream {
 /// #
 /// # impl ActiveModelBehavior for ActiveModel {}
 /// ```
+///
+/// A composite primary key can have at most six columns, as its values are held in a
+/// `sea_query::ValueTuple`. A primary key of more columns results in a compile error.
+///
+/// ```compile_fail
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "posts")]
+/// pub struct Model {
+///     #[sea_orm(primary_key, auto_increment = false)]
+///     pub a: i32,
+///     #[sea_orm(primary_key, auto_increment = false)]
+///     pub b: i32,
+///     #[sea_orm(primary_key, auto_increment = false)]
+///     pub c: i32,
+///     #[sea_orm(primary_key, auto_increment = false)]
+///     pub d: i32,
+///     #[sea_orm(primary_key, auto_increment = false)]
+///     pub e: i32,
+///     #[sea_orm(primary_key, auto_increment = false)]
+///     pub f: i32,
+///     #[sea_orm(primary_key, auto_increment = false)]
+///     pub g: i32,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// ```
 #[proc_macro_derive(DeriveEntityModel, attributes(sea_orm))]
 pub fn derive_entity_model(input: TokenStream) -> TokenStream {
     let input_ts = input.clone();
@@ -574,7 +605,53 @@ pub fn derive_active_enum(input: TokenStream) -> TokenStream {
 ///     num_of_fruits: i32,
 /// }
 /// ```
-#[proc_macro_derive(FromQueryResult)]
+///
+/// The fields can be renamed, skipped, or read from nested structs, e.g. for
+/// `SELECT "cake"."name", "fruit"."id" AS "fruit_id", "fruit"."name" AS "fruit_name" FROM "cake" LEFT JOIN "fruit" ...`
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, FromQueryResult};
+///
+/// #[derive(Debug, FromQueryResult)]
+/// struct Fruit {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// #[derive(Debug, FromQueryResult)]
+/// struct CakeWithFruit {
+///     #[sea_orm(alias = "name")]
+///     cake_name: String,
+///     #[sea_orm(nested, prefix = "fruit_")]
+///     fruit: Option<Fruit>,
+///     #[sea_orm(skip)]
+///     visited: bool,
+/// }
+/// ```
+///
+/// ### Attributes
+///
+/// - For the fields
+///     - `alias`: the column the field is read from
+///         - This attribute is optional with default value being the name of the field
+///     - `skip`: the field is not read, but set to its [`Default`] value
+///     - `nested`: the field is a struct which implements `FromQueryResult` itself and is read from the same row
+///         - If the field is an `Option`, it is `None` when the nested struct cannot be read, e.g. from the `NULL` columns of a `LEFT JOIN`
+///     - `prefix`: the prefix of the columns of a `nested` field, i.e. `prefix = "fruit_"`
+///         - This attribute is optional with default value being no prefix
+///
+/// Any other attribute is an error, but for the ones of [`DerivePartialModel`](derive.DerivePartialModel.html)
+///
+/// ```compile_fail
+/// use sea_orm::{entity::prelude::*, FromQueryResult};
+///
+/// #[derive(Debug, FromQueryResult)]
+/// struct SelectResult {
+///     #[sea_orm(rename = "name")]
+///     cake_name: String,
+/// }
+/// ```
+#[proc_macro_derive(FromQueryResult, attributes(sea_orm))]
 pub fn derive_from_query_result(input: TokenStream) -> TokenStream {
     let DeriveInput { ident, data, .. } = parse_macro_input!(input);
 
@@ -584,6 +661,63 @@ pub fn derive_from_query_result(input: TokenStream) -> TokenStream {
     }
 }
 
+/// The DerivePartialModel derive macro will implement [`sea_orm::PartialModelTrait`] for a struct,
+/// so that [`sea_orm::Select::into_partial_model`] selects exactly the columns of its fields.
+/// It has to be derived along with [`FromQueryResult`](derive.FromQueryResult.html).
+///
+/// ### Usage
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, sea_query::Expr, DerivePartialModel, FromQueryResult};
+/// # use sea_orm::tests_cfg::{cake, fruit};
+///
+/// #[derive(Debug, DerivePartialModel, FromQueryResult)]
+/// #[sea_orm(entity = "cake::Entity")]
+/// struct PartialCake {
+///     name: String,
+///     #[sea_orm(from_col = "id")]
+///     cake_id: i32,
+///     #[sea_orm(entity = "fruit::Entity", from_col = "name")]
+///     fruit_name: Option<String>,
+///     #[sea_orm(from_expr = r#"Expr::col((cake::Entity, cake::Column::Id)).add(1)"#)]
+///     next_id: i32,
+/// }
+/// ```
+///
+/// ### Attributes
+///
+/// - For the struct
+///     - `entity`: the Entity the columns are selected from, i.e. `entity = "cake::Entity"`
+///
+/// - For the fields
+///     - `from_col`: the column the field is selected from, in snake-case or camel-case
+///         - This attribute is optional with default value being the name of the field
+///     - `entity`: the Entity the column is selected from, e.g. an Entity joined to the query
+///         - This attribute is optional with default value being the `entity` of the struct
+///     - `from_expr`: an expression the field is selected from instead of a column
+///     - `alias` and `skip` of [`FromQueryResult`](derive.FromQueryResult.html): the field is selected as its `alias`, or not at all
+///
+/// Any other attribute is an error, including a `nested` field of [`FromQueryResult`](derive.FromQueryResult.html)
+///
+/// ```compile_fail
+/// use sea_orm::{entity::prelude::*, DerivePartialModel, FromQueryResult};
+/// # use sea_orm::tests_cfg::cake;
+///
+/// #[derive(Debug, DerivePartialModel, FromQueryResult)]
+/// #[sea_orm(entity = "cake::Entity")]
+/// struct PartialCake {
+///     #[sea_orm(from_column = "id")]
+///     cake_id: i32,
+/// }
+/// ```
+#[proc_macro_derive(DerivePartialModel, attributes(sea_orm))]
+pub fn derive_partial_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derives::expand_derive_partial_model(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 /// The DeriveRelation derive macro will implement RelationTrait for Relation.
 ///
 /// ### Usage
@@ -1,5 +1,7 @@
+use super::retry::delay;
 use crate::{
-    DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, Statement, TransactionError,
+    DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, RetryPolicy, Statement,
+    TransactionError,
 };
 use futures::Stream;
 use std::{future::Future, pin::Pin};
@@ -133,4 +135,82 @@ pub trait TransactionTrait {
             + Send,
         T: Send,
         E: std::error::Error + Send;
+
+    /// Execute SQL `BEGIN` transaction, with the unqualified table names referring to the given
+    /// schema throughout the transaction, see [DatabaseTransaction::set_schema].
+    /// Returns a Transaction that can be committed or rolled back
+    ///
+    /// Only PostgreSQL is supported, other backends return [DbErr::BackendNotSupported].
+    /// The schema is switched for this transaction only; to work against a schema outside of
+    /// a transaction, connect a separate pool with [ConnectOptions::set_schema_search_path](crate::ConnectOptions::set_schema_search_path).
+    async fn begin_with_schema(&self, schema: &str) -> Result<DatabaseTransaction, DbErr> {
+        let transaction = self.begin().await?;
+        transaction.set_schema(schema).await?;
+        Ok(transaction)
+    }
+
+    /// Execute the function inside a transaction, with the unqualified table names referring to
+    /// the given schema, see [DatabaseTransaction::set_schema].
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    ///
+    /// Like [TransactionTrait::begin_with_schema], only PostgreSQL is supported.
+    async fn transaction_with_schema<F, T, E>(
+        &self,
+        schema: &str,
+        callback: F,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        let transaction = self
+            .begin_with_schema(schema)
+            .await
+            .map_err(TransactionError::Connection)?;
+        transaction.run(callback).await
+    }
+
+    /// Execute the function inside a transaction with isolation level and/or access mode like
+    /// [TransactionTrait::transaction_with_config], running it again in a new transaction if it
+    /// fails with an error the [RetryPolicy] deems transient, e.g. a serialization failure.
+    /// The error from the last attempt is returned once the attempts are exhausted.
+    ///
+    /// An error returned by the function is inspected if it's a [DbErr] or caused by one.
+    async fn transaction_with_retry<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+        retry_policy: RetryPolicy,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> Fn(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send
+            + Sync,
+        T: Send,
+        E: std::error::Error + Send + 'static,
+    {
+        let mut attempt = 1;
+        loop {
+            let res = match self.begin_with_config(isolation_level, access_mode).await {
+                Ok(transaction) => transaction.run(&callback).await,
+                Err(err) => Err(TransactionError::Connection(err)),
+            };
+            match res {
+                Err(err)
+                    if attempt < retry_policy.max_attempts && retry_policy.should_retry(&err) =>
+                {
+                    delay(retry_policy.backoff_for(attempt)).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
 }
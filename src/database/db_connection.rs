@@ -1,18 +1,17 @@
+use super::interceptor;
 use crate::{
-    error::*, AccessMode, ConnectionTrait, DatabaseTransaction, ExecResult, IsolationLevel,
-    QueryResult, Statement, StatementBuilder, StreamTrait, TransactionError, TransactionTrait,
+    error::*, AccessMode, ConnectionTrait, DatabaseTransaction, ExecResult, Interceptor,
+    IsolationLevel, QueryResult, Statement, StatementBuilder, StreamTrait, TransactionError,
+    TransactionTrait,
 };
 use sea_query::{MysqlQueryBuilder, PostgresQueryBuilder, QueryBuilder, SqliteQueryBuilder};
-use std::{future::Future, pin::Pin};
+use std::{borrow::Cow, future::Future, pin::Pin, sync::Arc};
 use tracing::instrument;
 use url::Url;
 
 #[cfg(feature = "sqlx-dep")]
 use sqlx::pool::PoolConnection;
 
-#[cfg(feature = "mock")]
-use std::sync::Arc;
-
 /// Handle a database connection depending on the backend
 /// enabled by the feature flags. This creates a database pool.
 #[cfg_attr(not(feature = "mock"), derive(Clone))]
@@ -108,73 +107,106 @@ impl ConnectionTrait for DatabaseConnection {
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
-        match self {
-            #[cfg(feature = "sqlx-mysql")]
-            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.execute(stmt).await,
-            #[cfg(feature = "sqlx-postgres")]
-            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.execute(stmt).await,
-            #[cfg(feature = "sqlx-sqlite")]
-            DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.execute(stmt).await,
-            #[cfg(feature = "mock")]
-            DatabaseConnection::MockDatabaseConnection(conn) => conn.execute(stmt),
-            DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
-        }
+        interceptor::execute(&self.interceptors(), stmt, |stmt| async move {
+            match self {
+                #[cfg(feature = "sqlx-mysql")]
+                DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.execute(stmt).await,
+                #[cfg(feature = "sqlx-postgres")]
+                DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.execute(stmt).await,
+                #[cfg(feature = "sqlx-sqlite")]
+                DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.execute(stmt).await,
+                #[cfg(feature = "mock")]
+                DatabaseConnection::MockDatabaseConnection(conn) => {
+                    crate::metric::metric!(conn.metric_callback, &stmt, None, false, {
+                        conn.execute(stmt.clone())
+                    })
+                }
+                DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
+            }
+        })
+        .await
     }
 
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
-        match self {
-            #[cfg(feature = "sqlx-mysql")]
-            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.execute_unprepared(sql).await,
-            #[cfg(feature = "sqlx-postgres")]
-            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
-                conn.execute_unprepared(sql).await
-            }
-            #[cfg(feature = "sqlx-sqlite")]
-            DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
-                conn.execute_unprepared(sql).await
-            }
-            #[cfg(feature = "mock")]
-            DatabaseConnection::MockDatabaseConnection(conn) => {
-                let db_backend = conn.get_database_backend();
-                let stmt = Statement::from_string(db_backend, sql.into());
-                conn.execute(stmt)
+        let exec = |sql: String| async move {
+            match self {
+                #[cfg(feature = "sqlx-mysql")]
+                DatabaseConnection::SqlxMySqlPoolConnection(conn) => {
+                    conn.execute_unprepared(&sql).await
+                }
+                #[cfg(feature = "sqlx-postgres")]
+                DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                    conn.execute_unprepared(&sql).await
+                }
+                #[cfg(feature = "sqlx-sqlite")]
+                DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
+                    conn.execute_unprepared(&sql).await
+                }
+                #[cfg(feature = "mock")]
+                DatabaseConnection::MockDatabaseConnection(conn) => {
+                    let db_backend = conn.get_database_backend();
+                    let stmt = Statement::from_string(db_backend, sql);
+                    crate::metric::metric!(conn.metric_callback, &stmt, None, false, {
+                        conn.execute(stmt.clone())
+                    })
+                }
+                DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
             }
-            DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
+        };
+        let interceptors = self.interceptors();
+        if interceptors.is_empty() {
+            return exec(sql.to_owned()).await;
         }
+        let stmt = Statement::from_string(self.get_database_backend(), sql.to_owned());
+        interceptor::execute(&interceptors, stmt, |stmt| exec(stmt.sql)).await
     }
 
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
-        match self {
-            #[cfg(feature = "sqlx-mysql")]
-            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.query_one(stmt).await,
-            #[cfg(feature = "sqlx-postgres")]
-            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.query_one(stmt).await,
-            #[cfg(feature = "sqlx-sqlite")]
-            DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.query_one(stmt).await,
-            #[cfg(feature = "mock")]
-            DatabaseConnection::MockDatabaseConnection(conn) => conn.query_one(stmt),
-            DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
-        }
+        interceptor::query_one(&self.interceptors(), stmt, |stmt| async move {
+            match self {
+                #[cfg(feature = "sqlx-mysql")]
+                DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.query_one(stmt).await,
+                #[cfg(feature = "sqlx-postgres")]
+                DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.query_one(stmt).await,
+                #[cfg(feature = "sqlx-sqlite")]
+                DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.query_one(stmt).await,
+                #[cfg(feature = "mock")]
+                DatabaseConnection::MockDatabaseConnection(conn) => {
+                    crate::metric::metric!(conn.metric_callback, &stmt, None, false, {
+                        conn.query_one(stmt.clone())
+                    })
+                }
+                DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
+            }
+        })
+        .await
     }
 
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
-        match self {
-            #[cfg(feature = "sqlx-mysql")]
-            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.query_all(stmt).await,
-            #[cfg(feature = "sqlx-postgres")]
-            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.query_all(stmt).await,
-            #[cfg(feature = "sqlx-sqlite")]
-            DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.query_all(stmt).await,
-            #[cfg(feature = "mock")]
-            DatabaseConnection::MockDatabaseConnection(conn) => conn.query_all(stmt),
-            DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
-        }
+        interceptor::query_all(&self.interceptors(), stmt, |stmt| async move {
+            match self {
+                #[cfg(feature = "sqlx-mysql")]
+                DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.query_all(stmt).await,
+                #[cfg(feature = "sqlx-postgres")]
+                DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.query_all(stmt).await,
+                #[cfg(feature = "sqlx-sqlite")]
+                DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.query_all(stmt).await,
+                #[cfg(feature = "mock")]
+                DatabaseConnection::MockDatabaseConnection(conn) => {
+                    crate::metric::metric!(conn.metric_callback, &stmt, None, false, {
+                        conn.query_all(stmt.clone())
+                    })
+                }
+                DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
+            }
+        })
+        .await
     }
 
     #[cfg(feature = "mock")]
@@ -193,21 +225,29 @@ impl StreamTrait for DatabaseConnection {
         &'a self,
         stmt: Statement,
     ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
-        Box::pin(async move {
-            match self {
-                #[cfg(feature = "sqlx-mysql")]
-                DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.stream(stmt).await,
-                #[cfg(feature = "sqlx-postgres")]
-                DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.stream(stmt).await,
-                #[cfg(feature = "sqlx-sqlite")]
-                DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.stream(stmt).await,
-                #[cfg(feature = "mock")]
-                DatabaseConnection::MockDatabaseConnection(conn) => {
-                    Ok(crate::QueryStream::from((Arc::clone(conn), stmt, None)))
+        Box::pin(interceptor::stream(
+            self.interceptors().into_owned(),
+            stmt,
+            move |stmt| async move {
+                match self {
+                    #[cfg(feature = "sqlx-mysql")]
+                    DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.stream(stmt).await,
+                    #[cfg(feature = "sqlx-postgres")]
+                    DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.stream(stmt).await,
+                    #[cfg(feature = "sqlx-sqlite")]
+                    DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.stream(stmt).await,
+                    #[cfg(feature = "mock")]
+                    DatabaseConnection::MockDatabaseConnection(conn) => {
+                        Ok(crate::QueryStream::from((
+                            Arc::clone(conn),
+                            stmt,
+                            conn.metric_callback.clone(),
+                        )))
+                    }
+                    DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
                 }
-                DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
-            }
-        })
+            },
+        ))
     }
 }
 
@@ -224,7 +264,7 @@ impl TransactionTrait for DatabaseConnection {
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.begin(None, None).await,
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
-                DatabaseTransaction::new_mock(Arc::clone(conn), None).await
+                DatabaseTransaction::new_mock(Arc::clone(conn), conn.transaction_hooks()).await
             }
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
         }
@@ -251,7 +291,7 @@ impl TransactionTrait for DatabaseConnection {
             }
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
-                DatabaseTransaction::new_mock(Arc::clone(conn), None).await
+                DatabaseTransaction::new_mock(Arc::clone(conn), conn.transaction_hooks()).await
             }
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
         }
@@ -284,9 +324,10 @@ impl TransactionTrait for DatabaseConnection {
             }
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
-                let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None)
-                    .await
-                    .map_err(TransactionError::Connection)?;
+                let transaction =
+                    DatabaseTransaction::new_mock(Arc::clone(conn), conn.transaction_hooks())
+                        .await
+                        .map_err(TransactionError::Connection)?;
                 transaction.run(_callback).await
             }
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected").into()),
@@ -328,9 +369,10 @@ impl TransactionTrait for DatabaseConnection {
             }
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
-                let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None)
-                    .await
-                    .map_err(TransactionError::Connection)?;
+                let transaction =
+                    DatabaseTransaction::new_mock(Arc::clone(conn), conn.transaction_hooks())
+                        .await
+                        .map_err(TransactionError::Connection)?;
                 transaction.run(_callback).await
             }
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected").into()),
@@ -369,6 +411,10 @@ impl DatabaseConnection {
 
 impl DatabaseConnection {
     /// Sets a callback to metric this connection
+    ///
+    /// # Panics
+    ///
+    /// Panics if a mock connection is shared with an open transaction or stream.
     pub fn set_metric_callback<F>(&mut self, _callback: F)
     where
         F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
@@ -386,7 +432,64 @@ impl DatabaseConnection {
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
                 conn.set_metric_callback(_callback)
             }
-            _ => {}
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => {
+                Arc::get_mut(conn)
+                    .expect("Mock connection is in use")
+                    .metric_callback = Some(Arc::new(_callback))
+            }
+            DatabaseConnection::Disconnected => {}
+        }
+    }
+
+    /// Add an [Interceptor] to the stack of this connection. It will see every statement
+    /// executed on the connection, on its transactions and its streams.
+    ///
+    /// Returns an error if the connection is disconnected.
+    pub fn add_interceptor<I>(&mut self, interceptor: I) -> Result<(), DbErr>
+    where
+        I: Interceptor + 'static,
+    {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => {
+                conn.interceptors.push(Arc::new(interceptor))
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.interceptors.push(Arc::new(interceptor))
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
+                conn.interceptors.push(Arc::new(interceptor))
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => {
+                conn.add_interceptor(Arc::new(interceptor))
+            }
+            DatabaseConnection::Disconnected => {
+                return Err(conn_err(format!(
+                    "Cannot add {interceptor:?} to a disconnected connection"
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Borrowed from the sqlx connections, but copied out of the lock of a mock connection
+    fn interceptors(&self) -> Cow<'_, [Arc<dyn Interceptor>]> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => Cow::Borrowed(&conn.interceptors),
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                Cow::Borrowed(&conn.interceptors)
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => Cow::Borrowed(&conn.interceptors),
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => Cow::Owned(conn.interceptors()),
+            DatabaseConnection::Disconnected => Cow::Borrowed(&[]),
         }
     }
 
@@ -486,6 +589,25 @@ impl DbBackend {
     pub fn support_returning(&self) -> bool {
         matches!(self, Self::Postgres)
     }
+
+    /// The maximum number of bind parameters a single statement can carry: 65535 for MySQL
+    /// and Postgres, and 32766 for SQLite (since 3.32, older versions only allow 999,
+    /// see [Insert::max_bind_params](crate::Insert::max_bind_params))
+    pub fn max_bind_params(&self) -> usize {
+        match self {
+            Self::MySql | Self::Postgres => 65535,
+            Self::Sqlite => 32766,
+        }
+    }
+
+    /// Get the display name of the database backend
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MySql => "MySQL",
+            Self::Postgres => "PostgreSQL",
+            Self::Sqlite => "SQLite",
+        }
+    }
 }
 
 #[cfg(test)]
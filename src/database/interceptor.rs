@@ -0,0 +1,282 @@
+use crate::{error::*, ExecResult, QueryResult, Statement};
+use std::{fmt::Debug, future::Future, sync::Arc};
+
+/// Hooks into the execution of every [Statement] on a connection or a transaction.
+///
+/// Interceptors are stacked in the order they are added with
+/// [DatabaseConnection::add_interceptor](crate::DatabaseConnection::add_interceptor):
+/// the first one added sees the statement first and its result last.
+/// Transactions begun on the connection inherit its interceptors.
+pub trait Interceptor: Debug + Send + Sync {
+    /// Inspect a statement before it is executed. Return it as is or rewritten to run it,
+    /// or return an error to reject it, in which case it will not reach the database.
+    fn before(&self, stmt: Statement) -> Result<Statement, DbErr> {
+        Ok(stmt)
+    }
+
+    /// Inspect the result of a statement run with `execute` or `execute_unprepared`
+    fn after_execute(&self, _stmt: &Statement, _result: Result<&ExecResult, &DbErr>) {}
+
+    /// Inspect the rows returned by a query. For a stream this is called once it fails
+    /// to open, ends or is dropped, without its rows as they are not collected.
+    fn after_query(&self, _stmt: &Statement, _result: Result<&[QueryResult], &DbErr>) {}
+}
+
+pub(crate) type Interceptors = Vec<Arc<dyn Interceptor>>;
+
+/// A stream calling [Interceptor::after_query] once it ends or is dropped
+pub(crate) trait InterceptStream {
+    fn intercept(&mut self, interceptors: Interceptors);
+}
+
+/// Pass the statement through every interceptor, in order
+fn before(interceptors: &[Arc<dyn Interceptor>], stmt: Statement) -> Result<Statement, DbErr> {
+    interceptors
+        .iter()
+        .try_fold(stmt, |stmt, interceptor| interceptor.before(stmt))
+}
+
+pub(crate) async fn execute<F, Fut>(
+    interceptors: &[Arc<dyn Interceptor>],
+    stmt: Statement,
+    exec: F,
+) -> Result<ExecResult, DbErr>
+where
+    F: FnOnce(Statement) -> Fut,
+    Fut: Future<Output = Result<ExecResult, DbErr>>,
+{
+    if interceptors.is_empty() {
+        return exec(stmt).await;
+    }
+    let stmt = before(interceptors, stmt)?;
+    let res = exec(stmt.clone()).await;
+    for interceptor in interceptors.iter().rev() {
+        interceptor.after_execute(&stmt, res.as_ref());
+    }
+    res
+}
+
+pub(crate) async fn query_one<F, Fut>(
+    interceptors: &[Arc<dyn Interceptor>],
+    stmt: Statement,
+    query: F,
+) -> Result<Option<QueryResult>, DbErr>
+where
+    F: FnOnce(Statement) -> Fut,
+    Fut: Future<Output = Result<Option<QueryResult>, DbErr>>,
+{
+    if interceptors.is_empty() {
+        return query(stmt).await;
+    }
+    let stmt = before(interceptors, stmt)?;
+    let res = query(stmt.clone()).await;
+    let rows = match &res {
+        Ok(Some(row)) => Ok(std::slice::from_ref(row)),
+        Ok(None) => Ok(&[][..]),
+        Err(err) => Err(err),
+    };
+    for interceptor in interceptors.iter().rev() {
+        interceptor.after_query(&stmt, rows);
+    }
+    res
+}
+
+pub(crate) async fn query_all<F, Fut>(
+    interceptors: &[Arc<dyn Interceptor>],
+    stmt: Statement,
+    query: F,
+) -> Result<Vec<QueryResult>, DbErr>
+where
+    F: FnOnce(Statement) -> Fut,
+    Fut: Future<Output = Result<Vec<QueryResult>, DbErr>>,
+{
+    if interceptors.is_empty() {
+        return query(stmt).await;
+    }
+    let stmt = before(interceptors, stmt)?;
+    let res = query(stmt.clone()).await;
+    for interceptor in interceptors.iter().rev() {
+        interceptor.after_query(&stmt, res.as_deref());
+    }
+    res
+}
+
+pub(crate) async fn stream<F, Fut, S>(
+    interceptors: Interceptors,
+    stmt: Statement,
+    open: F,
+) -> Result<S, DbErr>
+where
+    F: FnOnce(Statement) -> Fut,
+    Fut: Future<Output = Result<S, DbErr>>,
+    S: InterceptStream,
+{
+    if interceptors.is_empty() {
+        return open(stmt).await;
+    }
+    let stmt = before(&interceptors, stmt)?;
+    match open(stmt.clone()).await {
+        Ok(mut stream) => {
+            stream.intercept(interceptors);
+            Ok(stream)
+        }
+        Err(err) => {
+            for interceptor in interceptors.iter().rev() {
+                interceptor.after_query(&stmt, Err(&err));
+            }
+            Err(err)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, error::*, tests_cfg::*, DatabaseConnection, DbBackend, ExecResult, Interceptor,
+        MockDatabase, MockExecResult, QueryFilter, QueryResult, Statement, Transaction,
+        TransactionTrait,
+    };
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct Tag(&'static str);
+
+    impl Interceptor for Tag {
+        fn before(&self, mut stmt: Statement) -> Result<Statement, DbErr> {
+            stmt.sql = format!("/* {} */ {}", self.0, stmt.sql);
+            Ok(stmt)
+        }
+    }
+
+    #[derive(Debug)]
+    struct RejectDeleteAll;
+
+    impl Interceptor for RejectDeleteAll {
+        fn before(&self, stmt: Statement) -> Result<Statement, DbErr> {
+            if stmt.sql.starts_with("DELETE") && !stmt.sql.contains("WHERE") {
+                return Err(DbErr::Custom("DELETE without WHERE".to_owned()));
+            }
+            Ok(stmt)
+        }
+    }
+
+    #[derive(Debug)]
+    struct Audit(Arc<Mutex<Vec<String>>>);
+
+    impl Interceptor for Audit {
+        fn after_execute(&self, stmt: &Statement, result: Result<&ExecResult, &DbErr>) {
+            let rows_affected = result.map_or(0, |res| res.rows_affected());
+            self.push(format!("{} -> {rows_affected} affected", stmt.sql));
+        }
+
+        fn after_query(&self, stmt: &Statement, result: Result<&[QueryResult], &DbErr>) {
+            let rows = result.map_or(0, |rows| rows.len());
+            self.push(format!("{} -> {rows} rows", stmt.sql));
+        }
+    }
+
+    impl Audit {
+        fn push(&self, entry: String) {
+            self.0
+                .lock()
+                .expect("Fail to acquire audit log")
+                .push(entry);
+        }
+    }
+
+    #[smol_potat::test]
+    async fn interceptors() -> Result<(), DbErr> {
+        let audit_log = Arc::new(Mutex::new(Vec::new()));
+        let mut db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                vec![
+                    cake::Model {
+                        id: 1,
+                        name: "Cheese Cake".to_owned(),
+                    },
+                    cake::Model {
+                        id: 2,
+                        name: "Chocolate Cake".to_owned(),
+                    },
+                ],
+                vec![cake::Model {
+                    id: 1,
+                    name: "Cheese Cake".to_owned(),
+                }],
+            ])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        db.add_interceptor(RejectDeleteAll)?;
+        db.add_interceptor(Audit(Arc::clone(&audit_log)))?;
+        db.add_interceptor(Tag("tenant_a"))?;
+
+        assert_eq!(cake::Entity::find().all(&db).await?.len(), 2);
+        {
+            let mut stream = cake::Entity::find()
+                .filter(cake::Column::Id.eq(1))
+                .stream(&db)
+                .await?;
+            assert!(stream.try_next().await?.is_some());
+        }
+        assert_eq!(
+            cake::Entity::delete_many().exec(&db).await,
+            Err(DbErr::Custom("DELETE without WHERE".to_owned()))
+        );
+        db.transaction::<_, _, DbErr>(|txn| {
+            Box::pin(async move { cake::Entity::delete_by_id(1).exec(txn).await })
+        })
+        .await
+        .expect("Fail to delete cake");
+
+        assert_eq!(
+            *audit_log.lock().expect("Fail to acquire audit log"),
+            [
+                r#"/* tenant_a */ SELECT "cake"."id", "cake"."name" FROM "cake" -> 2 rows"#,
+                r#"/* tenant_a */ SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = $1 -> 0 rows"#,
+                r#"/* tenant_a */ DELETE FROM "cake" WHERE "cake"."id" = $1 -> 1 affected"#,
+            ]
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"/* tenant_a */ SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    []
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"/* tenant_a */ SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = $1"#,
+                    [1i32.into()]
+                ),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"/* tenant_a */ DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                        [1i32.into()]
+                    ),
+                    Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+                ]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_interceptor_disconnected() {
+        assert_eq!(
+            DatabaseConnection::Disconnected.add_interceptor(Tag("tenant_a")),
+            Err(DbErr::Conn(RuntimeErr::Internal(
+                r#"Cannot add Tag("tenant_a") to a disconnected connection"#.to_owned()
+            )))
+        );
+    }
+}
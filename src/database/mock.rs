@@ -164,7 +164,7 @@ impl MockDatabaseTrait for MockDatabase {
     fn begin(&mut self) {
         match self.transaction.as_mut() {
             Some(transaction) => transaction.begin_nested(self.db_backend),
-            None => self.transaction = Some(OpenTransaction::init()),
+            None => self.transaction = Some(OpenTransaction::init(self.db_backend)),
         }
     }
 
@@ -196,6 +196,14 @@ impl MockDatabaseTrait for MockDatabase {
         }
     }
 
+    #[instrument(level = "trace")]
+    fn savepoint(&mut self, stmt: Statement) {
+        match self.transaction.as_mut() {
+            Some(transaction) => transaction.push(stmt),
+            None => panic!("There is no open transaction to create a savepoint in"),
+        }
+    }
+
     fn drain_transaction_log(&mut self) -> Vec<Transaction> {
         std::mem::take(&mut self.transaction_log)
     }
@@ -334,12 +342,9 @@ impl Transaction {
 }
 
 impl OpenTransaction {
-    fn init() -> Self {
+    fn init(db_backend: DbBackend) -> Self {
         Self {
-            stmts: vec![Statement::from_string(
-                DbBackend::Postgres,
-                "BEGIN".to_owned(),
-            )],
+            stmts: vec![Statement::from_string(db_backend, "BEGIN".to_owned())],
             transaction_depth: 0,
         }
     }
@@ -396,8 +401,8 @@ impl OpenTransaction {
 #[cfg(feature = "mock")]
 mod tests {
     use crate::{
-        entity::*, error::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase, Statement,
-        Transaction, TransactionError, TransactionTrait,
+        entity::*, error::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase,
+        MockExecResult, RetryPolicy, Statement, Transaction, TransactionError, TransactionTrait,
     };
     use pretty_assertions::assert_eq;
 
@@ -537,6 +542,176 @@ mod tests {
         );
     }
 
+    #[smol_potat::test]
+    async fn test_savepoint() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        let txn = db.begin().await?;
+        assert_eq!(txn.depth(), 1);
+        txn.savepoint("before_cake").await?;
+        let _ = cake::Entity::find().one(&txn).await;
+        txn.rollback_to_savepoint("before_cake").await?;
+        {
+            let nested = txn.begin().await?;
+            assert_eq!(nested.depth(), 2);
+            nested.savepoint("before_fruit").await?;
+            nested.release_savepoint("before_fruit").await?;
+            nested.commit().await?;
+        }
+        txn.release_savepoint("before_cake").await?;
+        txn.commit().await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::MySql, "BEGIN".to_owned()),
+                Statement::from_string(DbBackend::MySql, "SAVEPOINT `before_cake`".to_owned()),
+                Statement::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"SELECT `cake`.`id`, `cake`.`name` FROM `cake` LIMIT ?"#,
+                    [1u64.into()]
+                ),
+                Statement::from_string(
+                    DbBackend::MySql,
+                    "ROLLBACK TO SAVEPOINT `before_cake`".to_owned()
+                ),
+                Statement::from_string(DbBackend::MySql, "SAVEPOINT savepoint_1".to_owned()),
+                Statement::from_string(DbBackend::MySql, "SAVEPOINT `before_fruit`".to_owned()),
+                Statement::from_string(
+                    DbBackend::MySql,
+                    "RELEASE SAVEPOINT `before_fruit`".to_owned()
+                ),
+                Statement::from_string(
+                    DbBackend::MySql,
+                    "RELEASE SAVEPOINT savepoint_1".to_owned()
+                ),
+                Statement::from_string(
+                    DbBackend::MySql,
+                    "RELEASE SAVEPOINT `before_cake`".to_owned()
+                ),
+                Statement::from_string(DbBackend::MySql, "COMMIT".to_owned()),
+            ])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_transaction_with_retry() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let attempts = AtomicU32::new(0);
+
+        let mut retry_policy = RetryPolicy::new(3);
+        retry_policy
+            .backoff(std::time::Duration::ZERO)
+            .retry_if(|err| matches!(err, DbErr::RecordVersionMismatch));
+
+        let res = db
+            .transaction_with_retry::<_, u32, DbErr>(
+                |txn| {
+                    let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                    Box::pin(async move {
+                        let _ = cake::Entity::find().one(txn).await;
+                        match attempt {
+                            1 => Err(DbErr::RecordVersionMismatch),
+                            _ => Ok(attempt),
+                        }
+                    })
+                },
+                None,
+                None,
+                retry_policy.clone(),
+            )
+            .await;
+        assert!(matches!(res, Ok(2)));
+
+        let res = db
+            .transaction_with_retry::<_, (), DbErr>(
+                |_| Box::pin(async { Err(DbErr::RecordNotUpdated) }),
+                None,
+                None,
+                retry_policy,
+            )
+            .await;
+        assert!(matches!(
+            res,
+            Err(TransactionError::Transaction(DbErr::RecordNotUpdated))
+        ));
+
+        let select = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+            [1u64.into()],
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                    select.clone(),
+                    Statement::from_string(DbBackend::Postgres, "ROLLBACK".to_owned()),
+                ]),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                    select,
+                    Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+                ]),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                    Statement::from_string(DbBackend::Postgres, "ROLLBACK".to_owned()),
+                ]),
+            ]
+        );
+    }
+
+    #[smol_potat::test]
+    async fn test_transaction_with_schema() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        db.transaction_with_schema::<_, (), DbErr>("tenant_a", |txn| {
+            Box::pin(async move {
+                let _ = cake::Entity::find().one(txn).await;
+                Ok(())
+            })
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                Statement::from_string(
+                    DbBackend::Postgres,
+                    r#"SET LOCAL search_path = "tenant_a""#.to_owned()
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                    [1u64.into()]
+                ),
+                Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+            ])]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert_eq!(
+            db.begin_with_schema("tenant_a").await.map(|_| ()),
+            Err(DbErr::BackendNotSupported {
+                db: "MySQL",
+                ctx: "Setting the schema of a transaction",
+            })
+        );
+    }
+
     #[smol_potat::test]
     async fn test_nested_transaction_2() {
         let db = MockDatabase::new(DbBackend::Postgres).into_connection();
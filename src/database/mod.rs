@@ -1,19 +1,31 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 mod connection;
 mod db_connection;
+mod interceptor;
 #[cfg(feature = "mock")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
 mod mock;
+mod replica;
+mod retry;
+mod slow_query;
 mod statement;
 mod stream;
 mod transaction;
 
 pub use connection::*;
 pub use db_connection::*;
+pub use interceptor::Interceptor;
+pub(crate) use interceptor::Interceptors;
 #[cfg(feature = "mock")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
 pub use mock::*;
+pub use replica::*;
+pub use retry::*;
+#[allow(unused_imports)]
+pub(crate) use slow_query::slow_query;
+pub use slow_query::SlowQuery;
+pub(crate) use slow_query::SlowQueryLog;
 pub use statement::*;
 use std::borrow::Cow;
 pub use stream::*;
@@ -52,6 +64,8 @@ pub struct ConnectOptions {
     pub(crate) sqlcipher_key: Option<Cow<'static, str>>,
     /// Schema search path (PostgreSQL only)
     pub(crate) schema_search_path: Option<String>,
+    /// Log statements slower than a threshold
+    pub(crate) slow_query_log: SlowQueryLog,
 }
 
 impl Database {
@@ -119,6 +133,7 @@ impl ConnectOptions {
             sqlx_logging_level: log::LevelFilter::Info,
             sqlcipher_key: None,
             schema_search_path: None,
+            slow_query_log: SlowQueryLog::default(),
         }
     }
 
@@ -262,4 +277,44 @@ impl ConnectOptions {
         self.schema_search_path = Some(schema_search_path);
         self
     }
+
+    /// Log statements taking at least the given duration at warn level, along with their values.
+    /// Statements run inside of transactions are included.
+    pub fn slow_query_threshold(&mut self, value: Duration) -> &mut Self {
+        self.slow_query_log.threshold = Some(value);
+        self
+    }
+
+    /// Get the duration above which statements are logged as slow queries, if set
+    pub fn get_slow_query_threshold(&self) -> Option<Duration> {
+        self.slow_query_log.threshold
+    }
+
+    /// Run `EXPLAIN` on the slow queries to capture their query plan (default false).
+    /// It's run on the same connection right after the statement.
+    pub fn slow_query_explain(&mut self, value: bool) -> &mut Self {
+        self.slow_query_log.explain = value;
+        self
+    }
+
+    /// Get whether the query plan of slow queries is captured
+    pub fn get_slow_query_explain(&self) -> bool {
+        self.slow_query_log.explain
+    }
+
+    /// Set a callback receiving each [SlowQuery], e.g. to report it elsewhere than in the logs
+    pub fn slow_query_callback<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(&SlowQuery) + Send + Sync + 'static,
+    {
+        self.slow_query_log.callback = Some(Arc::new(callback));
+        self
+    }
+
+    #[cfg(feature = "sqlx-dep")]
+    pub(crate) fn get_slow_query_log(&self) -> Option<SlowQueryLog> {
+        self.slow_query_log
+            .threshold
+            .map(|_| self.slow_query_log.clone())
+    }
 }
@@ -0,0 +1,414 @@
+use crate::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr,
+    ExecResult, IsolationLevel, QueryResult, Statement, StreamTrait, TransactionError,
+    TransactionTrait,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+use tracing::instrument;
+
+/// A connection to a primary database and its read replicas.
+///
+/// Statements executed with `execute` and transactions go to the primary, while queries
+/// and streams are routed to one of the replicas, picked according to the [ReplicaSelection].
+/// Queries other than `SELECT`, such as an `INSERT ... RETURNING`, are writes and go to the primary.
+/// Without any replica everything goes to the primary.
+#[derive(Debug)]
+pub struct ReplicatedConnection {
+    primary: DatabaseConnection,
+    replicas: Vec<Replica>,
+    selection: ReplicaSelection,
+    pin_after_write: Option<Duration>,
+    last_write: Mutex<Option<Instant>>,
+    next: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct Replica {
+    conn: DatabaseConnection,
+    in_flight: AtomicUsize,
+}
+
+/// How a [ReplicatedConnection] picks the replica to run a query on
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ReplicaSelection {
+    /// Take turns among the replicas
+    #[default]
+    RoundRobin,
+    /// Pick the replica with the least number of queries in flight
+    LeastBusy,
+}
+
+/// Counts a query in flight on a replica until dropped
+struct InFlight<'a>(&'a AtomicUsize);
+
+impl<'a> InFlight<'a> {
+    fn new(counter: &'a AtomicUsize) -> Self {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Self(counter)
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl ReplicatedConnection {
+    /// Create a [ReplicatedConnection] from the connections to the primary and to the replicas,
+    /// which must all be of the same database backend
+    pub fn new<I>(primary: DatabaseConnection, replicas: I) -> Self
+    where
+        I: IntoIterator<Item = DatabaseConnection>,
+    {
+        Self {
+            primary,
+            replicas: replicas
+                .into_iter()
+                .map(|conn| Replica {
+                    conn,
+                    in_flight: AtomicUsize::new(0),
+                })
+                .collect(),
+            selection: ReplicaSelection::default(),
+            pin_after_write: None,
+            last_write: Mutex::new(None),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Set how the replica to run a query on is picked
+    pub fn selection(mut self, selection: ReplicaSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Route queries to the primary for the given duration after each write or transaction,
+    /// so that they see the changes before they are replicated
+    pub fn pin_after_write(mut self, duration: Duration) -> Self {
+        self.pin_after_write = Some(duration);
+        self
+    }
+
+    /// Get the connection to the primary, to read from it explicitly
+    pub fn primary(&self) -> &DatabaseConnection {
+        &self.primary
+    }
+
+    /// Get the connections to the replicas
+    pub fn replicas(&self) -> impl Iterator<Item = &DatabaseConnection> {
+        self.replicas.iter().map(|replica| &replica.conn)
+    }
+
+    /// Explicitly close the connections to the primary and the replicas
+    pub async fn close(self) -> Result<(), DbErr> {
+        self.primary.close().await?;
+        for replica in self.replicas {
+            replica.conn.close().await?;
+        }
+        Ok(())
+    }
+
+    fn mark_write(&self) {
+        if self.pin_after_write.is_some() {
+            *self.last_write.lock().expect("Fail to acquire last write") = Some(Instant::now());
+        }
+    }
+
+    fn is_pinned(&self) -> bool {
+        match self.pin_after_write {
+            Some(duration) => self
+                .last_write
+                .lock()
+                .expect("Fail to acquire last write")
+                .map_or(false, |last_write| last_write.elapsed() < duration),
+            None => false,
+        }
+    }
+
+    /// The connection to read from, along with the counter of queries in flight if it's a replica
+    fn reader(&self) -> (&DatabaseConnection, Option<&AtomicUsize>) {
+        if self.replicas.is_empty() || self.is_pinned() {
+            return (&self.primary, None);
+        }
+        let replica = match self.selection {
+            ReplicaSelection::RoundRobin => {
+                let next = self.next.fetch_add(1, Ordering::Relaxed);
+                &self.replicas[next % self.replicas.len()]
+            }
+            ReplicaSelection::LeastBusy => self
+                .replicas
+                .iter()
+                .min_by_key(|replica| replica.in_flight.load(Ordering::SeqCst))
+                .expect("There is at least one replica"),
+        };
+        (&replica.conn, Some(&replica.in_flight))
+    }
+}
+
+/// Whether a query can run on a replica, i.e. it's a `SELECT` without `RETURNING`
+fn is_read_only(stmt: &Statement) -> bool {
+    let sql = stmt.sql.trim_start();
+    sql.get(..6)
+        .map_or(false, |keyword| keyword.eq_ignore_ascii_case("SELECT"))
+        && !sql.to_ascii_uppercase().contains("RETURNING")
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for ReplicatedConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        self.primary.get_database_backend()
+    }
+
+    #[instrument(level = "trace")]
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        let res = self.primary.execute(stmt).await;
+        self.mark_write();
+        res
+    }
+
+    #[instrument(level = "trace")]
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        let res = self.primary.execute_unprepared(sql).await;
+        self.mark_write();
+        res
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        if !is_read_only(&stmt) {
+            let res = self.primary.query_one(stmt).await;
+            self.mark_write();
+            return res;
+        }
+        let (conn, in_flight) = self.reader();
+        let _in_flight = in_flight.map(InFlight::new);
+        conn.query_one(stmt).await
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        if !is_read_only(&stmt) {
+            let res = self.primary.query_all(stmt).await;
+            self.mark_write();
+            return res;
+        }
+        let (conn, in_flight) = self.reader();
+        let _in_flight = in_flight.map(InFlight::new);
+        conn.query_all(stmt).await
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.primary.is_mock_connection()
+    }
+}
+
+impl StreamTrait for ReplicatedConnection {
+    type Stream<'a> = crate::QueryStream;
+
+    #[instrument(level = "trace")]
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        if !is_read_only(&stmt) {
+            self.mark_write();
+            return self.primary.stream(stmt);
+        }
+        // the stream holds on to a pooled connection of its own,
+        // so it's only counted as in flight until it's opened
+        let (conn, in_flight) = self.reader();
+        Box::pin(async move {
+            let _in_flight = in_flight.map(InFlight::new);
+            conn.stream(stmt).await
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for ReplicatedConnection {
+    #[instrument(level = "trace")]
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.mark_write();
+        self.primary.begin().await
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.mark_write();
+        self.primary
+            .begin_with_config(isolation_level, access_mode)
+            .await
+    }
+
+    /// Execute the function inside a transaction on the primary.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        let res = self.primary.transaction(callback).await;
+        self.mark_write();
+        res
+    }
+
+    /// Execute the function inside a transaction on the primary with isolation level and/or access mode.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        let res = self
+            .primary
+            .transaction_with_config(callback, isolation_level, access_mode)
+            .await;
+        self.mark_write();
+        res
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, error::*, tests_cfg::*, DbBackend, MockDatabase, MockExecResult,
+        ReplicaSelection, ReplicatedConnection, Statement, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[smol_potat::test]
+    async fn replicated_connection() -> Result<(), DbErr> {
+        let cheese = cake::Model {
+            id: 1,
+            name: "Cheese Cake".to_owned(),
+        };
+        let replica = || {
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([[cheese.clone()], [cheese.clone()]])
+                .into_connection()
+        };
+        let primary = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_query_results([[cheese.clone()]])
+            .into_connection();
+
+        let db = ReplicatedConnection::new(primary, [replica(), replica()])
+            .selection(ReplicaSelection::RoundRobin)
+            .pin_after_write(Duration::from_secs(60));
+
+        for _ in 0..4 {
+            assert_eq!(cake::Entity::find().one(&db).await?, Some(cheese.clone()));
+        }
+        cake::Entity::delete_many().exec(&db).await?;
+        // read from the primary until the write is replicated
+        assert_eq!(cake::Entity::find().one(&db).await?, Some(cheese.clone()));
+
+        let select = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+            [1u64.into()],
+        );
+        let ReplicatedConnection {
+            primary, replicas, ..
+        } = db;
+        assert_eq!(
+            primary.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(DbBackend::Postgres, r#"DELETE FROM "cake""#, []),
+                Transaction::one(select.clone()),
+            ]
+        );
+        for replica in replicas {
+            assert_eq!(
+                replica.conn.into_transaction_log(),
+                Transaction::wrap([select.clone(), select.clone()])
+            );
+        }
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_on_primary() -> Result<(), DbErr> {
+        let cheese = cake::Model {
+            id: 1,
+            name: "Cheese Cake".to_owned(),
+        };
+        let primary = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cheese.clone()], [cheese.clone()]])
+            .into_connection();
+        let replica = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let db =
+            ReplicatedConnection::new(primary, [replica]).pin_after_write(Duration::from_secs(60));
+
+        assert_eq!(
+            cake::ActiveModel {
+                name: Set("Cheese Cake".to_owned()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await?,
+            cheese
+        );
+        // read from the primary until the write is replicated
+        assert_eq!(cake::Entity::find().one(&db).await?, Some(cheese.clone()));
+
+        let ReplicatedConnection {
+            primary, replicas, ..
+        } = db;
+        assert_eq!(
+            primary.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("name") VALUES ($1) RETURNING "id", "name""#,
+                    ["Cheese Cake".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                    [1u64.into()]
+                ),
+            ]
+        );
+        for replica in replicas {
+            assert_eq!(replica.conn.into_transaction_log(), []);
+        }
+
+        Ok(())
+    }
+}
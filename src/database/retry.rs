@@ -0,0 +1,155 @@
+use crate::{error::*, TransactionError};
+use std::{fmt, sync::Arc, time::Duration};
+
+/// Defines how [TransactionTrait::transaction_with_retry](crate::TransactionTrait::transaction_with_retry)
+/// re-runs a transaction which failed with a transient error
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one
+    pub(crate) max_attempts: u32,
+    /// The delay before the first retry
+    pub(crate) backoff: Duration,
+    /// The factor the delay is multiplied by after each retry
+    pub(crate) backoff_factor: u32,
+    /// The upper bound of the delay between attempts
+    pub(crate) max_backoff: Duration,
+    /// Decides whether a failed transaction should be retried
+    pub(crate) retry_if: Arc<dyn Fn(&DbErr) -> bool + Send + Sync>,
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .field("backoff_factor", &self.backoff_factor)
+            .field("max_backoff", &self.max_backoff)
+            .finish()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl RetryPolicy {
+    /// Create a [RetryPolicy] making at most `max_attempts` attempts, with an exponential backoff
+    /// starting from 10ms. By default only serialization failures, deadlocks, lock wait timeouts
+    /// and busy databases are retried, see [is_transient_err].
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff: Duration::from_millis(10),
+            backoff_factor: 2,
+            max_backoff: Duration::from_secs(1),
+            retry_if: Arc::new(is_transient_err),
+        }
+    }
+
+    /// Set the delay before the first retry (default 10ms)
+    pub fn backoff(&mut self, value: Duration) -> &mut Self {
+        self.backoff = value;
+        self
+    }
+
+    /// Set the factor the delay is multiplied by after each retry (default 2)
+    pub fn backoff_factor(&mut self, value: u32) -> &mut Self {
+        self.backoff_factor = value;
+        self
+    }
+
+    /// Set the upper bound of the delay between attempts (default 1s)
+    pub fn max_backoff(&mut self, value: Duration) -> &mut Self {
+        self.max_backoff = value;
+        self
+    }
+
+    /// Retry on the errors matching the predicate instead of the transient ones
+    pub fn retry_if<F>(&mut self, predicate: F) -> &mut Self
+    where
+        F: Fn(&DbErr) -> bool + Send + Sync + 'static,
+    {
+        self.retry_if = Arc::new(predicate);
+        self
+    }
+
+    /// Get the maximum number of attempts
+    pub fn get_max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// The delay before the given retry, starting from 1
+    pub(crate) fn backoff_for(&self, retry: u32) -> Duration {
+        let factor = self
+            .backoff_factor
+            .checked_pow(retry.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+
+    /// Whether the transaction should be run again after failing with the error,
+    /// which may be returned by the callback or from committing
+    pub(crate) fn should_retry<E>(&self, err: &TransactionError<E>) -> bool
+    where
+        E: std::error::Error + 'static,
+    {
+        match err {
+            TransactionError::Connection(err) => (self.retry_if)(err),
+            TransactionError::Transaction(err) => {
+                let mut err: Option<&(dyn std::error::Error + 'static)> = Some(err);
+                while let Some(e) = err {
+                    if let Some(err) = e.downcast_ref::<DbErr>() {
+                        return (self.retry_if)(err);
+                    }
+                    err = e.source();
+                }
+                false
+            }
+        }
+    }
+}
+
+/// Check if the error is a transient one, such that re-running the transaction may succeed:
+/// a [SqlErr::Deadlock], [SqlErr::SerializationFailure] or [SqlErr::LockTimeout]
+pub fn is_transient_err(err: &DbErr) -> bool {
+    matches!(
+        err.sql_err(),
+        Some(
+            SqlErr::Deadlock { .. }
+                | SqlErr::SerializationFailure { .. }
+                | SqlErr::LockTimeout { .. }
+        )
+    )
+}
+
+/// Wait for the duration on the timer of the async runtime. Without any runtime feature,
+/// a thread is spawned to wait on.
+pub(crate) async fn delay(duration: Duration) {
+    if duration.is_zero() {
+        return;
+    }
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-actix"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(all(
+        feature = "runtime-async-std",
+        not(any(feature = "runtime-tokio", feature = "runtime-actix"))
+    ))]
+    async_std::task::sleep(duration).await;
+    #[cfg(not(any(
+        feature = "runtime-tokio",
+        feature = "runtime-actix",
+        feature = "runtime-async-std"
+    )))]
+    {
+        let (tx, rx) = futures::channel::oneshot::channel();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            let _ = tx.send(());
+        });
+        let _ = rx.await;
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{error::*, DbBackend, QueryResult, Statement};
+use std::{fmt, sync::Arc, time::Duration};
+
+/// A statement which took longer than the slow query threshold set with
+/// [ConnectOptions::slow_query_threshold](crate::ConnectOptions::slow_query_threshold)
+#[derive(Debug)]
+pub struct SlowQuery {
+    /// How long the statement took to execute
+    pub elapsed: Duration,
+    /// The statement, along with its bound values
+    pub statement: Statement,
+    /// The query plan of the statement, if [ConnectOptions::slow_query_explain](crate::ConnectOptions::slow_query_explain)
+    /// is enabled: the JSON output of `EXPLAIN` on MySQL and PostgreSQL,
+    /// or the details of `EXPLAIN QUERY PLAN` on SQLite, one step per line.
+    /// It is an error if the statement cannot be explained.
+    pub explain: Option<Result<String, DbErr>>,
+}
+
+pub(crate) type SlowQueryCallback = Arc<dyn Fn(&SlowQuery) + Send + Sync>;
+
+/// Logs the statements slower than the threshold, and hands them over to the callback
+#[derive(Clone, Default)]
+pub(crate) struct SlowQueryLog {
+    pub(crate) threshold: Option<Duration>,
+    pub(crate) explain: bool,
+    pub(crate) callback: Option<SlowQueryCallback>,
+}
+
+impl fmt::Debug for SlowQueryLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlowQueryLog")
+            .field("threshold", &self.threshold)
+            .field("explain", &self.explain)
+            .finish()
+    }
+}
+
+#[allow(unused_imports)]
+pub(crate) use inner::slow_query;
+
+mod inner {
+    /// Time the code and report the statement to the [SlowQueryLog](super::SlowQueryLog)
+    /// if it's slow, explaining it on the given sqlx connection if needed
+    #[allow(unused_macros)]
+    macro_rules! slow_query {
+        ($slow_query_log:expr, $stmt:expr, $conn:expr, $sqlx_query:path, $code:block) => {{
+            let _start = $slow_query_log.is_some().then(std::time::SystemTime::now);
+            let res = $code;
+            if let (Some(_start), Some(log)) = (_start, $slow_query_log.as_ref()) {
+                let elapsed = _start.elapsed().unwrap_or_default();
+                if log.is_slow(elapsed) {
+                    let explain = match (log.explain, crate::SlowQueryLog::explain_statement($stmt))
+                    {
+                        (true, Some(explain)) => Some(
+                            $sqlx_query(&explain)
+                                .fetch_all($conn)
+                                .await
+                                .map_err(crate::sqlx_error_to_query_err)
+                                .and_then(|rows| {
+                                    crate::SlowQueryLog::explain_output(
+                                        $stmt.db_backend,
+                                        rows.into_iter().map(Into::into).collect(),
+                                    )
+                                }),
+                        ),
+                        _ => None,
+                    };
+                    log.report(crate::SlowQuery {
+                        elapsed,
+                        statement: $stmt.clone(),
+                        explain,
+                    });
+                }
+            }
+            res
+        }};
+    }
+    pub(crate) use slow_query;
+}
+
+#[cfg_attr(not(feature = "sqlx-dep"), allow(dead_code))]
+impl SlowQueryLog {
+    pub(crate) fn is_slow(&self, elapsed: Duration) -> bool {
+        self.threshold
+            .map_or(false, |threshold| elapsed >= threshold)
+    }
+
+    /// The statement to explain the given one with, unless it cannot be explained
+    pub(crate) fn explain_statement(stmt: &Statement) -> Option<Statement> {
+        let operation = stmt.sql.split_whitespace().next().unwrap_or_default();
+        if !["SELECT", "INSERT", "UPDATE", "DELETE", "WITH"]
+            .iter()
+            .any(|keyword| operation.eq_ignore_ascii_case(keyword))
+        {
+            return None;
+        }
+        let prefix = match stmt.db_backend {
+            DbBackend::MySql => "EXPLAIN FORMAT=JSON",
+            DbBackend::Postgres => "EXPLAIN (FORMAT JSON)",
+            DbBackend::Sqlite => "EXPLAIN QUERY PLAN",
+        };
+        Some(Statement {
+            sql: format!("{prefix} {}", stmt.sql),
+            values: stmt.values.clone(),
+            db_backend: stmt.db_backend,
+            table: stmt.table.clone(),
+        })
+    }
+
+    /// Render the rows returned by the explain statement
+    pub(crate) fn explain_output(
+        backend: DbBackend,
+        rows: Vec<QueryResult>,
+    ) -> Result<String, DbErr> {
+        let first = || {
+            rows.first()
+                .ok_or_else(|| DbErr::RecordNotFound("No query plan".to_owned()))
+        };
+        match backend {
+            DbBackend::MySql => first()?.try_get("", "EXPLAIN"),
+            #[cfg(feature = "with-json")]
+            DbBackend::Postgres => first()?
+                .try_get::<serde_json::Value>("", "QUERY PLAN")
+                .map(|plan| plan.to_string()),
+            #[cfg(not(feature = "with-json"))]
+            DbBackend::Postgres => first()?.try_get("", "QUERY PLAN"),
+            DbBackend::Sqlite => rows
+                .iter()
+                .map(|row| row.try_get::<String>("", "detail"))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|details| details.join("\n")),
+        }
+    }
+
+    /// Log the slow query and hand it over to the callback
+    pub(crate) fn report(&self, slow_query: SlowQuery) {
+        tracing::warn!(
+            "Slow query took {:?}: {}",
+            slow_query.elapsed,
+            slow_query.statement
+        );
+        if let Some(Ok(explain)) = &slow_query.explain {
+            tracing::warn!("Query plan: {}", explain);
+        }
+        if let Some(callback) = &self.callback {
+            callback(&slow_query);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::SlowQueryLog;
+    use crate::{error::*, ConnectionTrait, DbBackend, MockDatabase, SlowQuery, Statement};
+    use pretty_assertions::assert_eq;
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    #[smol_potat::test]
+    async fn explain_slow_query() -> Result<(), DbErr> {
+        let stmt = Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            r#"SELECT "cake"."id" FROM "cake" WHERE "cake"."name" = ?"#,
+            ["Cheese Cake".into()],
+        );
+        let explain = SlowQueryLog::explain_statement(&stmt).expect("SELECT can be explained");
+        assert_eq!(
+            explain,
+            Statement::from_sql_and_values(
+                DbBackend::Sqlite,
+                r#"EXPLAIN QUERY PLAN SELECT "cake"."id" FROM "cake" WHERE "cake"."name" = ?"#,
+                ["Cheese Cake".into()],
+            )
+        );
+        assert_eq!(
+            SlowQueryLog::explain_statement(&Statement::from_string(
+                DbBackend::Postgres,
+                "VACUUM".to_owned()
+            )),
+            None
+        );
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([[
+                maplit::btreemap! { "detail" => "SEARCH cake USING INDEX idx_name (name=?)".into() },
+                maplit::btreemap! { "detail" => "USE TEMP B-TREE FOR ORDER BY".into() },
+            ]])
+            .into_connection();
+        let rows = db.query_all(explain).await?;
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let log = SlowQueryLog {
+            threshold: Some(Duration::from_millis(100)),
+            explain: true,
+            callback: Some({
+                let reported = Arc::clone(&reported);
+                Arc::new(move |slow_query: &SlowQuery| {
+                    let explain = slow_query
+                        .explain
+                        .as_ref()
+                        .and_then(|res| res.as_ref().ok());
+                    reported
+                        .lock()
+                        .expect("Fail to acquire slow queries")
+                        .push((slow_query.statement.clone(), explain.cloned()))
+                })
+            }),
+        };
+        assert!(log.is_slow(Duration::from_millis(250)));
+        log.report(SlowQuery {
+            elapsed: Duration::from_millis(250),
+            statement: stmt.clone(),
+            explain: Some(SlowQueryLog::explain_output(DbBackend::Sqlite, rows)),
+        });
+
+        assert_eq!(
+            *reported.lock().expect("Fail to acquire slow queries"),
+            [(
+                stmt,
+                Some(
+                    "SEARCH cake USING INDEX idx_name (name=?)\nUSE TEMP B-TREE FOR ORDER BY"
+                        .to_owned()
+                )
+            )]
+        );
+
+        Ok(())
+    }
+}
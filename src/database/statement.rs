@@ -1,10 +1,13 @@
 use crate::DbBackend;
-use sea_query::{inject_parameters, MysqlQueryBuilder, PostgresQueryBuilder, SqliteQueryBuilder};
+use sea_query::{
+    inject_parameters, MysqlQueryBuilder, PostgresQueryBuilder, QueryStatementBuilder,
+    SqliteQueryBuilder,
+};
 pub use sea_query::{Value, Values};
 use std::fmt;
 
 /// Defines an SQL statement
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Statement {
     /// The SQL query
     pub sql: String,
@@ -13,6 +16,16 @@ pub struct Statement {
     /// The database backend this statement is constructed for.
     /// The SQL dialect and values should be valid for the DbBackend.
     pub db_backend: DbBackend,
+    /// The table the statement operates on, used to name its tracing span.
+    /// It is recorded by the query builder when the feature flag `tracing-spans` is enabled,
+    /// and is left out when comparing statements.
+    pub table: Option<String>,
+}
+
+impl PartialEq for Statement {
+    fn eq(&self, other: &Self) -> bool {
+        self.sql == other.sql && self.values == other.values && self.db_backend == other.db_backend
+    }
 }
 
 /// Any type that can build a [Statement]
@@ -28,6 +41,7 @@ impl Statement {
             sql: stmt,
             values: None,
             db_backend,
+            table: None,
         }
     }
 
@@ -51,7 +65,21 @@ impl Statement {
             sql: stmt.0,
             values: Some(stmt.1),
             db_backend,
+            table: None,
+        }
+    }
+
+    /// Record the table of the query statement this was built from
+    #[allow(unused_mut, unused_variables)]
+    pub(crate) fn with_table_of<S>(mut self, query: &S) -> Statement
+    where
+        S: QueryStatementBuilder,
+    {
+        #[cfg(feature = "tracing-spans")]
+        {
+            self.table = crate::tracing_spans::table_of(query);
         }
+        self
     }
 }
 
@@ -97,7 +125,7 @@ macro_rules! build_query_stmt {
         impl StatementBuilder for $stmt {
             fn build(&self, db_backend: &DbBackend) -> Statement {
                 let stmt = build_any_stmt!(self, db_backend);
-                Statement::from_string_values_tuple(*db_backend, stmt)
+                Statement::from_string_values_tuple(*db_backend, stmt).with_table_of(self)
             }
         }
     };
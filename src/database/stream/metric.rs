@@ -2,12 +2,19 @@ use std::{pin::Pin, task::Poll, time::Duration};
 
 use futures::Stream;
 
-use crate::{DbErr, QueryResult, Statement};
+use crate::{error::*, Interceptors, QueryResult, Statement};
 
 pub(crate) struct MetricStream<'a> {
     metric_callback: &'a Option<crate::metric::Callback>,
     stmt: &'a Statement,
     elapsed: Option<Duration>,
+    in_transaction: bool,
+    rows_returned: u64,
+    failed: bool,
+    interceptors: Interceptors,
+    error: Option<DbErr>,
+    #[cfg(feature = "tracing-spans")]
+    span: Option<tracing::Span>,
     stream: Pin<Box<dyn Stream<Item = Result<QueryResult, DbErr>> + 'a + Send>>,
 }
 
@@ -17,6 +24,7 @@ impl<'a> MetricStream<'a> {
         metric_callback: &'a Option<crate::metric::Callback>,
         stmt: &'a Statement,
         elapsed: Option<Duration>,
+        in_transaction: bool,
         stream: S,
     ) -> Self
     where
@@ -26,9 +34,58 @@ impl<'a> MetricStream<'a> {
             metric_callback,
             stmt,
             elapsed,
+            in_transaction,
+            rows_returned: 0,
+            failed: false,
+            interceptors: Vec::new(),
+            error: None,
+            #[cfg(feature = "tracing-spans")]
+            span: None,
             stream: Box::pin(stream),
         }
     }
+
+    /// Let the interceptors inspect the result of the stream once it ends or is dropped
+    pub(crate) fn intercept(&mut self, interceptors: Interceptors) {
+        self.interceptors = interceptors;
+    }
+
+    /// Record the rows returned, or the error, on the span of the statement
+    /// once the stream ends or is dropped
+    #[cfg(feature = "tracing-spans")]
+    pub(crate) fn trace(&mut self, span: tracing::Span) {
+        self.span = Some(span);
+    }
+
+    /// Hand the outcome of the stream over once it ends or is dropped
+    fn finish(&mut self) {
+        if !self.interceptors.is_empty() {
+            self.after_query();
+        }
+        #[cfg(feature = "tracing-spans")]
+        if let Some(span) = self.span.take() {
+            span.record("db.rows_returned", self.rows_returned);
+        }
+    }
+
+    fn after_query(&mut self) {
+        let interceptors = std::mem::take(&mut self.interceptors);
+        let result = match &self.error {
+            Some(err) => Err(err),
+            None => Ok(&[][..]),
+        };
+        for interceptor in interceptors.iter().rev() {
+            interceptor.after_query(self.stmt, result);
+        }
+    }
+}
+
+/// A copy of a stream error for the interceptors, as the error itself is handed over to the caller
+fn copy_err(err: &DbErr) -> DbErr {
+    match err {
+        DbErr::Query(err) => DbErr::Query(RuntimeErr::Internal(err.to_string())),
+        err => DbErr::Custom(err.to_string()),
+    }
 }
 
 impl<'a> Stream for MetricStream<'a> {
@@ -44,6 +101,21 @@ impl<'a> Stream for MetricStream<'a> {
             .is_some()
             .then(std::time::SystemTime::now);
         let res = Pin::new(&mut this.stream).poll_next(cx);
+        match &res {
+            Poll::Ready(Some(Ok(_))) => this.rows_returned += 1,
+            Poll::Ready(Some(Err(err))) => {
+                this.failed = true;
+                if !this.interceptors.is_empty() && this.error.is_none() {
+                    this.error = Some(copy_err(err));
+                }
+                #[cfg(feature = "tracing-spans")]
+                if let Some(span) = this.span.take() {
+                    crate::tracing_spans::record_error(&span, err);
+                }
+            }
+            Poll::Ready(None) => this.finish(),
+            Poll::Pending => {}
+        }
         if let (Some(_start), Some(elapsed)) = (_start, &mut this.elapsed) {
             *elapsed += _start.elapsed().unwrap_or_default();
         }
@@ -53,11 +125,17 @@ impl<'a> Stream for MetricStream<'a> {
 
 impl<'a> Drop for MetricStream<'a> {
     fn drop(&mut self) {
+        self.finish();
         if let (Some(callback), Some(elapsed)) = (self.metric_callback.as_deref(), self.elapsed) {
             let info = crate::metric::Info {
                 elapsed,
                 statement: self.stmt,
-                failed: false,
+                failed: self.failed,
+                backend: self.stmt.db_backend,
+                rows_affected: None,
+                rows_returned: Some(self.rows_returned),
+                pool_wait: None,
+                in_transaction: self.in_transaction,
             };
             callback(&info);
         }
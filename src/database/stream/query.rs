@@ -16,7 +16,10 @@ use sqlx::{pool::PoolConnection, Executor};
 use super::metric::MetricStream;
 #[cfg(feature = "sqlx-dep")]
 use crate::driver::*;
-use crate::{DbErr, InnerConnection, QueryResult, Statement};
+use crate::{
+    database::interceptor::InterceptStream, DbErr, InnerConnection, Interceptors, QueryResult,
+    Statement,
+};
 
 /// Creates a stream from a [QueryResult]
 #[ouroboros::self_referencing]
@@ -132,7 +135,7 @@ impl QueryStream {
                         .map_ok(Into::into)
                         .map_err(sqlx_error_to_query_err);
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
-                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                    MetricStream::new(_metric_callback, stmt, elapsed, false, stream)
                 }
                 #[cfg(feature = "sqlx-postgres")]
                 InnerConnection::Postgres(c) => {
@@ -143,7 +146,7 @@ impl QueryStream {
                         .map_ok(Into::into)
                         .map_err(sqlx_error_to_query_err);
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
-                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                    MetricStream::new(_metric_callback, stmt, elapsed, false, stream)
                 }
                 #[cfg(feature = "sqlx-sqlite")]
                 InnerConnection::Sqlite(c) => {
@@ -154,14 +157,14 @@ impl QueryStream {
                         .map_ok(Into::into)
                         .map_err(sqlx_error_to_query_err);
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
-                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                    MetricStream::new(_metric_callback, stmt, elapsed, false, stream)
                 }
                 #[cfg(feature = "mock")]
                 InnerConnection::Mock(c) => {
                     let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
                     let stream = c.fetch(stmt);
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
-                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                    MetricStream::new(_metric_callback, stmt, elapsed, false, stream)
                 }
                 #[allow(unreachable_patterns)]
                 _ => unreachable!(),
@@ -171,6 +174,20 @@ impl QueryStream {
     }
 }
 
+impl QueryStream {
+    /// Record the rows returned on the span of the statement once the stream ends
+    #[cfg(feature = "tracing-spans")]
+    pub(crate) fn trace_rows(&mut self, span: tracing::Span) {
+        self.with_stream_mut(|stream| stream.trace(span))
+    }
+}
+
+impl InterceptStream for QueryStream {
+    fn intercept(&mut self, interceptors: Interceptors) {
+        self.with_stream_mut(|stream| stream.intercept(interceptors))
+    }
+}
+
 impl Stream for QueryStream {
     type Item = Result<QueryResult, DbErr>;
 
@@ -13,7 +13,10 @@ use sqlx::Executor;
 use super::metric::MetricStream;
 #[cfg(feature = "sqlx-dep")]
 use crate::driver::*;
-use crate::{DbErr, InnerConnection, QueryResult, Statement};
+use crate::{
+    database::interceptor::InterceptStream, DbErr, InnerConnection, Interceptors, QueryResult,
+    Statement,
+};
 
 /// `TransactionStream` cannot be used in a `transaction` closure as it does not impl `Send`.
 /// It seems to be a Rust limitation right now, and solution to work around this deemed to be extremely hard.
@@ -55,7 +58,7 @@ impl<'a> TransactionStream<'a> {
                         .map_ok(Into::into)
                         .map_err(sqlx_error_to_query_err);
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
-                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                    MetricStream::new(_metric_callback, stmt, elapsed, true, stream)
                 }
                 #[cfg(feature = "sqlx-postgres")]
                 InnerConnection::Postgres(c) => {
@@ -66,7 +69,7 @@ impl<'a> TransactionStream<'a> {
                         .map_ok(Into::into)
                         .map_err(sqlx_error_to_query_err);
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
-                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                    MetricStream::new(_metric_callback, stmt, elapsed, true, stream)
                 }
                 #[cfg(feature = "sqlx-sqlite")]
                 InnerConnection::Sqlite(c) => {
@@ -77,14 +80,14 @@ impl<'a> TransactionStream<'a> {
                         .map_ok(Into::into)
                         .map_err(sqlx_error_to_query_err);
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
-                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                    MetricStream::new(_metric_callback, stmt, elapsed, true, stream)
                 }
                 #[cfg(feature = "mock")]
                 InnerConnection::Mock(c) => {
                     let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
                     let stream = c.fetch(stmt);
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
-                    MetricStream::new(_metric_callback, stmt, elapsed, stream)
+                    MetricStream::new(_metric_callback, stmt, elapsed, true, stream)
                 }
                 #[allow(unreachable_patterns)]
                 _ => unreachable!(),
@@ -94,6 +97,20 @@ impl<'a> TransactionStream<'a> {
     }
 }
 
+impl<'a> TransactionStream<'a> {
+    /// Record the rows returned on the span of the statement once the stream ends
+    #[cfg(feature = "tracing-spans")]
+    pub(crate) fn trace_rows(&mut self, span: tracing::Span) {
+        self.with_stream_mut(|stream| stream.trace(span))
+    }
+}
+
+impl<'a> InterceptStream for TransactionStream<'a> {
+    fn intercept(&mut self, interceptors: Interceptors) {
+        self.with_stream_mut(|stream| stream.intercept(interceptors))
+    }
+}
+
 impl<'a> Stream for TransactionStream<'a> {
     type Item = Result<QueryResult, DbErr>;
 
@@ -1,11 +1,13 @@
+use super::interceptor::{self, Interceptors};
 use crate::{
     debug_print, error::*, AccessMode, ConnectionTrait, DbBackend, DbErr, ExecResult,
-    InnerConnection, IsolationLevel, QueryResult, Statement, StreamTrait, TransactionStream,
-    TransactionTrait,
+    InnerConnection, IsolationLevel, QueryResult, SlowQueryLog, Statement, StreamTrait,
+    TransactionStream, TransactionTrait,
 };
 #[cfg(feature = "sqlx-dep")]
 use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
 use futures::lock::Mutex;
+use sea_query::{Alias, Iden};
 #[cfg(feature = "sqlx-dep")]
 use sqlx::{pool::PoolConnection, TransactionManager};
 use std::{future::Future, pin::Pin, sync::Arc};
@@ -18,7 +20,20 @@ pub struct DatabaseTransaction {
     conn: Arc<Mutex<InnerConnection>>,
     backend: DbBackend,
     open: bool,
-    metric_callback: Option<crate::metric::Callback>,
+    depth: u32,
+    hooks: TransactionHooks,
+    #[cfg(feature = "tracing-spans")]
+    span: tracing::Span,
+}
+
+/// The callbacks a transaction inherits from its connection, and passes on to nested transactions
+#[derive(Clone)]
+pub(crate) struct TransactionHooks {
+    pub(crate) metric_callback: Option<crate::metric::Callback>,
+    pub(crate) interceptors: Interceptors,
+    // only the sqlx drivers log slow queries
+    #[cfg_attr(not(feature = "sqlx-dep"), allow(dead_code))]
+    pub(crate) slow_query_log: Option<SlowQueryLog>,
 }
 
 impl std::fmt::Debug for DatabaseTransaction {
@@ -31,16 +46,17 @@ impl DatabaseTransaction {
     #[cfg(feature = "sqlx-mysql")]
     pub(crate) async fn new_mysql(
         inner: PoolConnection<sqlx::MySql>,
-        metric_callback: Option<crate::metric::Callback>,
+        hooks: TransactionHooks,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::MySql(inner))),
             DbBackend::MySql,
-            metric_callback,
+            hooks,
             isolation_level,
             access_mode,
+            None,
         )
         .await
     }
@@ -48,16 +64,17 @@ impl DatabaseTransaction {
     #[cfg(feature = "sqlx-postgres")]
     pub(crate) async fn new_postgres(
         inner: PoolConnection<sqlx::Postgres>,
-        metric_callback: Option<crate::metric::Callback>,
+        hooks: TransactionHooks,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::Postgres(inner))),
             DbBackend::Postgres,
-            metric_callback,
+            hooks,
             isolation_level,
             access_mode,
+            None,
         )
         .await
     }
@@ -65,16 +82,17 @@ impl DatabaseTransaction {
     #[cfg(feature = "sqlx-sqlite")]
     pub(crate) async fn new_sqlite(
         inner: PoolConnection<sqlx::Sqlite>,
-        metric_callback: Option<crate::metric::Callback>,
+        hooks: TransactionHooks,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::Sqlite(inner))),
             DbBackend::Sqlite,
-            metric_callback,
+            hooks,
             isolation_level,
             access_mode,
+            None,
         )
         .await
     }
@@ -82,73 +100,97 @@ impl DatabaseTransaction {
     #[cfg(feature = "mock")]
     pub(crate) async fn new_mock(
         inner: Arc<crate::MockDatabaseConnection>,
-        metric_callback: Option<crate::metric::Callback>,
+        hooks: TransactionHooks,
     ) -> Result<DatabaseTransaction, DbErr> {
         let backend = inner.get_database_backend();
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::Mock(inner))),
             backend,
-            metric_callback,
+            hooks,
+            None,
             None,
             None,
         )
         .await
     }
 
-    #[instrument(level = "trace", skip(metric_callback))]
+    #[instrument(level = "trace", skip(hooks))]
     async fn begin(
         conn: Arc<Mutex<InnerConnection>>,
         backend: DbBackend,
-        metric_callback: Option<crate::metric::Callback>,
+        hooks: TransactionHooks,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
+        parent: Option<&DatabaseTransaction>,
     ) -> Result<DatabaseTransaction, DbErr> {
         let res = DatabaseTransaction {
             conn,
             backend,
             open: true,
-            metric_callback,
+            depth: parent.map_or(1, |parent| parent.depth + 1),
+            hooks,
+            #[cfg(feature = "tracing-spans")]
+            span: crate::tracing_spans::transaction_span(
+                backend,
+                parent.map(|parent| &parent.span),
+            ),
         };
-        match *res.conn.lock().await {
-            #[cfg(feature = "sqlx-mysql")]
-            InnerConnection::MySql(ref mut c) => {
-                // in MySQL SET TRANSACTION operations must be executed before transaction start
-                crate::driver::sqlx_mysql::set_transaction_config(c, isolation_level, access_mode)
-                    .await?;
-                <sqlx::MySql as sqlx::Database>::TransactionManager::begin(c)
-                    .await
-                    .map_err(sqlx_error_to_query_err)
-            }
-            #[cfg(feature = "sqlx-postgres")]
-            InnerConnection::Postgres(ref mut c) => {
-                <sqlx::Postgres as sqlx::Database>::TransactionManager::begin(c)
-                    .await
-                    .map_err(sqlx_error_to_query_err)?;
-                // in PostgreSQL SET TRANSACTION operations must be executed inside transaction
-                crate::driver::sqlx_postgres::set_transaction_config(
-                    c,
-                    isolation_level,
-                    access_mode,
-                )
-                .await
-            }
-            #[cfg(feature = "sqlx-sqlite")]
-            InnerConnection::Sqlite(ref mut c) => {
-                // in SQLite isolation level and access mode are global settings
-                crate::driver::sqlx_sqlite::set_transaction_config(c, isolation_level, access_mode)
-                    .await?;
-                <sqlx::Sqlite as sqlx::Database>::TransactionManager::begin(c)
-                    .await
-                    .map_err(sqlx_error_to_query_err)
-            }
-            #[cfg(feature = "mock")]
-            InnerConnection::Mock(ref mut c) => {
-                c.begin();
-                Ok(())
+        crate::metric::metric!(
+            res.hooks.metric_callback,
+            &Statement::from_string(res.backend, "BEGIN".to_owned()),
+            None,
+            true,
+            {
+                match *res.conn.lock().await {
+                    #[cfg(feature = "sqlx-mysql")]
+                    InnerConnection::MySql(ref mut c) => {
+                        // in MySQL SET TRANSACTION operations must be executed before transaction start
+                        crate::driver::sqlx_mysql::set_transaction_config(
+                            c,
+                            isolation_level,
+                            access_mode,
+                        )
+                        .await?;
+                        <sqlx::MySql as sqlx::Database>::TransactionManager::begin(c)
+                            .await
+                            .map_err(sqlx_error_to_query_err)
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    InnerConnection::Postgres(ref mut c) => {
+                        <sqlx::Postgres as sqlx::Database>::TransactionManager::begin(c)
+                            .await
+                            .map_err(sqlx_error_to_query_err)?;
+                        // in PostgreSQL SET TRANSACTION operations must be executed inside transaction
+                        crate::driver::sqlx_postgres::set_transaction_config(
+                            c,
+                            isolation_level,
+                            access_mode,
+                        )
+                        .await
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    InnerConnection::Sqlite(ref mut c) => {
+                        // in SQLite isolation level and access mode are global settings
+                        crate::driver::sqlx_sqlite::set_transaction_config(
+                            c,
+                            isolation_level,
+                            access_mode,
+                        )
+                        .await?;
+                        <sqlx::Sqlite as sqlx::Database>::TransactionManager::begin(c)
+                            .await
+                            .map_err(sqlx_error_to_query_err)
+                    }
+                    #[cfg(feature = "mock")]
+                    InnerConnection::Mock(ref mut c) => {
+                        c.begin();
+                        Ok(())
+                    }
+                    #[allow(unreachable_patterns)]
+                    _ => Err::<(), _>(conn_err("Disconnected")),
+                }
             }
-            #[allow(unreachable_patterns)]
-            _ => Err(conn_err("Disconnected")),
-        }?;
+        )?;
         Ok(res)
     }
 
@@ -179,33 +221,41 @@ impl DatabaseTransaction {
     #[instrument(level = "trace")]
     #[allow(unreachable_code, unused_mut)]
     pub async fn commit(mut self) -> Result<(), DbErr> {
-        match *self.conn.lock().await {
-            #[cfg(feature = "sqlx-mysql")]
-            InnerConnection::MySql(ref mut c) => {
-                <sqlx::MySql as sqlx::Database>::TransactionManager::commit(c)
-                    .await
-                    .map_err(sqlx_error_to_query_err)
-            }
-            #[cfg(feature = "sqlx-postgres")]
-            InnerConnection::Postgres(ref mut c) => {
-                <sqlx::Postgres as sqlx::Database>::TransactionManager::commit(c)
-                    .await
-                    .map_err(sqlx_error_to_query_err)
-            }
-            #[cfg(feature = "sqlx-sqlite")]
-            InnerConnection::Sqlite(ref mut c) => {
-                <sqlx::Sqlite as sqlx::Database>::TransactionManager::commit(c)
-                    .await
-                    .map_err(sqlx_error_to_query_err)
-            }
-            #[cfg(feature = "mock")]
-            InnerConnection::Mock(ref mut c) => {
-                c.commit();
-                Ok(())
+        crate::metric::metric!(
+            self.hooks.metric_callback,
+            &Statement::from_string(self.backend, "COMMIT".to_owned()),
+            None,
+            true,
+            {
+                match *self.conn.lock().await {
+                    #[cfg(feature = "sqlx-mysql")]
+                    InnerConnection::MySql(ref mut c) => {
+                        <sqlx::MySql as sqlx::Database>::TransactionManager::commit(c)
+                            .await
+                            .map_err(sqlx_error_to_query_err)
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    InnerConnection::Postgres(ref mut c) => {
+                        <sqlx::Postgres as sqlx::Database>::TransactionManager::commit(c)
+                            .await
+                            .map_err(sqlx_error_to_query_err)
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    InnerConnection::Sqlite(ref mut c) => {
+                        <sqlx::Sqlite as sqlx::Database>::TransactionManager::commit(c)
+                            .await
+                            .map_err(sqlx_error_to_query_err)
+                    }
+                    #[cfg(feature = "mock")]
+                    InnerConnection::Mock(ref mut c) => {
+                        c.commit();
+                        Ok(())
+                    }
+                    #[allow(unreachable_patterns)]
+                    _ => Err::<(), _>(conn_err("Disconnected")),
+                }
             }
-            #[allow(unreachable_patterns)]
-            _ => Err(conn_err("Disconnected")),
-        }?;
+        )?;
         self.open = false;
         Ok(())
     }
@@ -214,37 +264,137 @@ impl DatabaseTransaction {
     #[instrument(level = "trace")]
     #[allow(unreachable_code, unused_mut)]
     pub async fn rollback(mut self) -> Result<(), DbErr> {
-        match *self.conn.lock().await {
-            #[cfg(feature = "sqlx-mysql")]
-            InnerConnection::MySql(ref mut c) => {
-                <sqlx::MySql as sqlx::Database>::TransactionManager::rollback(c)
-                    .await
-                    .map_err(sqlx_error_to_query_err)
-            }
-            #[cfg(feature = "sqlx-postgres")]
-            InnerConnection::Postgres(ref mut c) => {
-                <sqlx::Postgres as sqlx::Database>::TransactionManager::rollback(c)
-                    .await
-                    .map_err(sqlx_error_to_query_err)
-            }
-            #[cfg(feature = "sqlx-sqlite")]
-            InnerConnection::Sqlite(ref mut c) => {
-                <sqlx::Sqlite as sqlx::Database>::TransactionManager::rollback(c)
-                    .await
-                    .map_err(sqlx_error_to_query_err)
-            }
-            #[cfg(feature = "mock")]
-            InnerConnection::Mock(ref mut c) => {
-                c.rollback();
-                Ok(())
+        crate::metric::metric!(
+            self.hooks.metric_callback,
+            &Statement::from_string(self.backend, "ROLLBACK".to_owned()),
+            None,
+            true,
+            {
+                match *self.conn.lock().await {
+                    #[cfg(feature = "sqlx-mysql")]
+                    InnerConnection::MySql(ref mut c) => {
+                        <sqlx::MySql as sqlx::Database>::TransactionManager::rollback(c)
+                            .await
+                            .map_err(sqlx_error_to_query_err)
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    InnerConnection::Postgres(ref mut c) => {
+                        <sqlx::Postgres as sqlx::Database>::TransactionManager::rollback(c)
+                            .await
+                            .map_err(sqlx_error_to_query_err)
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    InnerConnection::Sqlite(ref mut c) => {
+                        <sqlx::Sqlite as sqlx::Database>::TransactionManager::rollback(c)
+                            .await
+                            .map_err(sqlx_error_to_query_err)
+                    }
+                    #[cfg(feature = "mock")]
+                    InnerConnection::Mock(ref mut c) => {
+                        c.rollback();
+                        Ok(())
+                    }
+                    #[allow(unreachable_patterns)]
+                    _ => Err::<(), _>(conn_err("Disconnected")),
+                }
             }
-            #[allow(unreachable_patterns)]
-            _ => Err(conn_err("Disconnected")),
-        }?;
+        )?;
         self.open = false;
         Ok(())
     }
 
+    /// The nesting depth of the transaction: 1 for a transaction begun on a connection,
+    /// and one more for each transaction begun inside of it, which is backed by a savepoint
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// Set the schema the unqualified table names refer to for the rest of the transaction,
+    /// so that entities without a `schema_name` work against the given schema, e.g. of a tenant.
+    /// If set inside a nested transaction, it's undone when that transaction is rolled back.
+    ///
+    /// Only PostgreSQL supports this, by `SET LOCAL search_path`, other backends return
+    /// [DbErr::BackendNotSupported]. The schema is reset once the transaction ends, so it never
+    /// carries over to the next use of the pooled connection. MySQL's `USE` could not guarantee
+    /// that, as it outlives the transaction.
+    #[instrument(level = "trace")]
+    pub async fn set_schema(&self, schema: &str) -> Result<(), DbErr> {
+        if self.backend != DbBackend::Postgres {
+            return Err(DbErr::BackendNotSupported {
+                db: self.backend.as_str(),
+                ctx: "Setting the schema of a transaction",
+            });
+        }
+        let mut sql = "SET LOCAL search_path = ".to_owned();
+        Alias::new(schema).prepare(&mut sql, self.backend.get_query_builder().quote());
+        self.execute_unprepared(&sql).await.map(|_| ())
+    }
+
+    /// Create a savepoint with the given name, marking a point within the transaction
+    /// which can be rolled back to without aborting the whole transaction
+    #[instrument(level = "trace")]
+    pub async fn savepoint(&self, name: &str) -> Result<(), DbErr> {
+        self.exec_savepoint("SAVEPOINT", name).await
+    }
+
+    /// Release the savepoint with the given name, keeping the changes made since it was created
+    #[instrument(level = "trace")]
+    pub async fn release_savepoint(&self, name: &str) -> Result<(), DbErr> {
+        self.exec_savepoint("RELEASE SAVEPOINT", name).await
+    }
+
+    /// Roll back the changes made since the savepoint with the given name was created.
+    /// The savepoint itself is kept and can be rolled back to again.
+    #[instrument(level = "trace")]
+    pub async fn rollback_to_savepoint(&self, name: &str) -> Result<(), DbErr> {
+        self.exec_savepoint("ROLLBACK TO SAVEPOINT", name).await
+    }
+
+    #[allow(unused_variables)]
+    async fn exec_savepoint(&self, command: &str, name: &str) -> Result<(), DbErr> {
+        let mut sql = format!("{command} ");
+        Alias::new(name).prepare(&mut sql, self.backend.get_query_builder().quote());
+        let stmt = Statement::from_string(self.backend, sql);
+
+        interceptor::execute(&self.hooks.interceptors, stmt, |stmt| async move {
+            debug_print!("{}", stmt);
+
+            crate::tracing_spans::db_span!(&stmt, parent: &self.span, {
+                crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                    match &mut *self.conn.lock().await {
+                        #[cfg(feature = "sqlx-mysql")]
+                        InnerConnection::MySql(conn) => {
+                            sqlx::Executor::execute(conn, stmt.sql.as_str())
+                                .await
+                                .map(Into::into)
+                                .map_err(sqlx_error_to_exec_err)
+                        }
+                        #[cfg(feature = "sqlx-postgres")]
+                        InnerConnection::Postgres(conn) => {
+                            sqlx::Executor::execute(conn, stmt.sql.as_str())
+                                .await
+                                .map(Into::into)
+                                .map_err(sqlx_error_to_exec_err)
+                        }
+                        #[cfg(feature = "sqlx-sqlite")]
+                        InnerConnection::Sqlite(conn) => {
+                            sqlx::Executor::execute(conn, stmt.sql.as_str())
+                                .await
+                                .map(Into::into)
+                                .map_err(sqlx_error_to_exec_err)
+                        }
+                        #[cfg(feature = "mock")]
+                        InnerConnection::Mock(conn) => conn.savepoint(stmt.clone()),
+                        #[allow(unreachable_patterns)]
+                        _ => Err(conn_err("Disconnected")),
+                    }
+                })
+            })
+        })
+        .await
+        .map(|_| ())
+    }
+
     // the rollback is queued and will be performed on next async operation, like returning the connection to the pool
     #[instrument(level = "trace")]
     fn start_rollback(&mut self) -> Result<(), DbErr> {
@@ -306,156 +456,281 @@ impl ConnectionTrait for DatabaseTransaction {
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
-        debug_print!("{}", stmt);
-
-        match &mut *self.conn.lock().await {
-            #[cfg(feature = "sqlx-mysql")]
-            InnerConnection::MySql(conn) => {
-                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query.execute(conn).await.map(Into::into)
-                })
-                .map_err(sqlx_error_to_exec_err)
-            }
-            #[cfg(feature = "sqlx-postgres")]
-            InnerConnection::Postgres(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query.execute(conn).await.map(Into::into)
-                })
-                .map_err(sqlx_error_to_exec_err)
-            }
-            #[cfg(feature = "sqlx-sqlite")]
-            InnerConnection::Sqlite(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query.execute(conn).await.map(Into::into)
-                })
-                .map_err(sqlx_error_to_exec_err)
-            }
-            #[cfg(feature = "mock")]
-            InnerConnection::Mock(conn) => return conn.execute(stmt),
-            #[allow(unreachable_patterns)]
-            _ => Err(conn_err("Disconnected")),
-        }
+        interceptor::execute(&self.hooks.interceptors, stmt, |stmt| async move {
+            debug_print!("{}", stmt);
+
+            crate::tracing_spans::db_span!(&stmt, parent: &self.span, {
+                match &mut *self.conn.lock().await {
+                    #[cfg(feature = "sqlx-mysql")]
+                    InnerConnection::MySql(conn) => {
+                        let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                        crate::slow_query!(
+                            self.hooks.slow_query_log,
+                            &stmt,
+                            conn,
+                            crate::driver::sqlx_mysql::sqlx_query,
+                            {
+                                crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                                    query
+                                        .execute(&mut *conn)
+                                        .await
+                                        .map(Into::into)
+                                        .map_err(sqlx_error_to_exec_err)
+                                })
+                            }
+                        )
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    InnerConnection::Postgres(conn) => {
+                        let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
+                        crate::slow_query!(
+                            self.hooks.slow_query_log,
+                            &stmt,
+                            conn,
+                            crate::driver::sqlx_postgres::sqlx_query,
+                            {
+                                crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                                    query
+                                        .execute(&mut *conn)
+                                        .await
+                                        .map(Into::into)
+                                        .map_err(sqlx_error_to_exec_err)
+                                })
+                            }
+                        )
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    InnerConnection::Sqlite(conn) => {
+                        let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+                        crate::slow_query!(
+                            self.hooks.slow_query_log,
+                            &stmt,
+                            conn,
+                            crate::driver::sqlx_sqlite::sqlx_query,
+                            {
+                                crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                                    query
+                                        .execute(&mut *conn)
+                                        .await
+                                        .map(Into::into)
+                                        .map_err(sqlx_error_to_exec_err)
+                                })
+                            }
+                        )
+                    }
+                    #[cfg(feature = "mock")]
+                    InnerConnection::Mock(conn) => {
+                        crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                            conn.execute(stmt.clone())
+                        })
+                    }
+                    #[allow(unreachable_patterns)]
+                    _ => Err(conn_err("Disconnected")),
+                }
+            })
+        })
+        .await
     }
 
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
-        debug_print!("{}", sql);
-
-        match &mut *self.conn.lock().await {
-            #[cfg(feature = "sqlx-mysql")]
-            InnerConnection::MySql(conn) => sqlx::Executor::execute(conn, sql)
-                .await
-                .map(Into::into)
-                .map_err(sqlx_error_to_exec_err),
-            #[cfg(feature = "sqlx-postgres")]
-            InnerConnection::Postgres(conn) => sqlx::Executor::execute(conn, sql)
-                .await
-                .map(Into::into)
-                .map_err(sqlx_error_to_exec_err),
-            #[cfg(feature = "sqlx-sqlite")]
-            InnerConnection::Sqlite(conn) => sqlx::Executor::execute(conn, sql)
-                .await
-                .map(Into::into)
-                .map_err(sqlx_error_to_exec_err),
-            #[cfg(feature = "mock")]
-            InnerConnection::Mock(conn) => {
-                let db_backend = conn.get_database_backend();
-                let stmt = Statement::from_string(db_backend, sql.into());
-                conn.execute(stmt)
-            }
-            #[allow(unreachable_patterns)]
-            _ => Err(conn_err("Disconnected")),
+        let exec = |sql: String| async move {
+            debug_print!("{}", sql);
+
+            crate::tracing_spans::db_span!(self.backend, &sql, parent: &self.span, {
+                crate::metric::metric!(
+                    self.hooks.metric_callback,
+                    &Statement::from_string(self.backend, sql.clone()),
+                    None,
+                    true,
+                    {
+                        match &mut *self.conn.lock().await {
+                            #[cfg(feature = "sqlx-mysql")]
+                            InnerConnection::MySql(conn) => sqlx::Executor::execute(conn, sql.as_str())
+                                .await
+                                .map(Into::into)
+                                .map_err(sqlx_error_to_exec_err),
+                            #[cfg(feature = "sqlx-postgres")]
+                            InnerConnection::Postgres(conn) => sqlx::Executor::execute(conn, sql.as_str())
+                                .await
+                                .map(Into::into)
+                                .map_err(sqlx_error_to_exec_err),
+                            #[cfg(feature = "sqlx-sqlite")]
+                            InnerConnection::Sqlite(conn) => sqlx::Executor::execute(conn, sql.as_str())
+                                .await
+                                .map(Into::into)
+                                .map_err(sqlx_error_to_exec_err),
+                            #[cfg(feature = "mock")]
+                            InnerConnection::Mock(conn) => {
+                                let db_backend = conn.get_database_backend();
+                                let stmt = Statement::from_string(db_backend, sql.clone());
+                                conn.execute(stmt)
+                            }
+                            #[allow(unreachable_patterns)]
+                            _ => Err(conn_err("Disconnected")),
+                        }
+                    }
+                )
+            })
+        };
+        if self.hooks.interceptors.is_empty() {
+            return exec(sql.to_owned()).await;
         }
+        let stmt = Statement::from_string(self.get_database_backend(), sql.to_owned());
+        interceptor::execute(&self.hooks.interceptors, stmt, |stmt| exec(stmt.sql)).await
     }
 
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
-        debug_print!("{}", stmt);
-
-        match &mut *self.conn.lock().await {
-            #[cfg(feature = "sqlx-mysql")]
-            InnerConnection::MySql(conn) => {
-                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    Self::map_err_ignore_not_found(
-                        query.fetch_one(conn).await.map(|row| Some(row.into())),
-                    )
-                })
-            }
-            #[cfg(feature = "sqlx-postgres")]
-            InnerConnection::Postgres(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    Self::map_err_ignore_not_found(
-                        query.fetch_one(conn).await.map(|row| Some(row.into())),
-                    )
-                })
-            }
-            #[cfg(feature = "sqlx-sqlite")]
-            InnerConnection::Sqlite(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    Self::map_err_ignore_not_found(
-                        query.fetch_one(conn).await.map(|row| Some(row.into())),
-                    )
-                })
-            }
-            #[cfg(feature = "mock")]
-            InnerConnection::Mock(conn) => return conn.query_one(stmt),
-            #[allow(unreachable_patterns)]
-            _ => Err(conn_err("Disconnected")),
-        }
+        interceptor::query_one(&self.hooks.interceptors, stmt, |stmt| async move {
+            debug_print!("{}", stmt);
+
+            crate::tracing_spans::db_span!(&stmt, parent: &self.span, {
+                match &mut *self.conn.lock().await {
+                    #[cfg(feature = "sqlx-mysql")]
+                    InnerConnection::MySql(conn) => {
+                        let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                        crate::slow_query!(
+                            self.hooks.slow_query_log,
+                            &stmt,
+                            conn,
+                            crate::driver::sqlx_mysql::sqlx_query,
+                            {
+                                crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                                    Self::map_err_ignore_not_found(
+                                        query.fetch_one(&mut *conn).await.map(|row| Some(row.into())),
+                                    )
+                                })
+                            }
+                        )
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    InnerConnection::Postgres(conn) => {
+                        let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
+                        crate::slow_query!(
+                            self.hooks.slow_query_log,
+                            &stmt,
+                            conn,
+                            crate::driver::sqlx_postgres::sqlx_query,
+                            {
+                                crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                                    Self::map_err_ignore_not_found(
+                                        query.fetch_one(&mut *conn).await.map(|row| Some(row.into())),
+                                    )
+                                })
+                            }
+                        )
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    InnerConnection::Sqlite(conn) => {
+                        let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+                        crate::slow_query!(
+                            self.hooks.slow_query_log,
+                            &stmt,
+                            conn,
+                            crate::driver::sqlx_sqlite::sqlx_query,
+                            {
+                                crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                                    Self::map_err_ignore_not_found(
+                                        query.fetch_one(&mut *conn).await.map(|row| Some(row.into())),
+                                    )
+                                })
+                            }
+                        )
+                    }
+                    #[cfg(feature = "mock")]
+                    InnerConnection::Mock(conn) => {
+                        crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                            conn.query_one(stmt.clone())
+                        })
+                    }
+                    #[allow(unreachable_patterns)]
+                    _ => Err(conn_err("Disconnected")),
+                }
+            })
+        })
+        .await
     }
 
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
-        debug_print!("{}", stmt);
-
-        match &mut *self.conn.lock().await {
-            #[cfg(feature = "sqlx-mysql")]
-            InnerConnection::MySql(conn) => {
-                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query
-                        .fetch_all(conn)
-                        .await
-                        .map(|rows| rows.into_iter().map(|r| r.into()).collect())
-                        .map_err(sqlx_error_to_query_err)
-                })
-            }
-            #[cfg(feature = "sqlx-postgres")]
-            InnerConnection::Postgres(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query
-                        .fetch_all(conn)
-                        .await
-                        .map(|rows| rows.into_iter().map(|r| r.into()).collect())
-                        .map_err(sqlx_error_to_query_err)
-                })
-            }
-            #[cfg(feature = "sqlx-sqlite")]
-            InnerConnection::Sqlite(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query
-                        .fetch_all(conn)
-                        .await
-                        .map(|rows| rows.into_iter().map(|r| r.into()).collect())
-                        .map_err(sqlx_error_to_query_err)
-                })
-            }
-            #[cfg(feature = "mock")]
-            InnerConnection::Mock(conn) => return conn.query_all(stmt),
-            #[allow(unreachable_patterns)]
-            _ => Err(conn_err("Disconnected")),
-        }
+        interceptor::query_all(&self.hooks.interceptors, stmt, |stmt| async move {
+            debug_print!("{}", stmt);
+
+            crate::tracing_spans::db_span!(&stmt, parent: &self.span, {
+                match &mut *self.conn.lock().await {
+                    #[cfg(feature = "sqlx-mysql")]
+                    InnerConnection::MySql(conn) => {
+                        let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                        crate::slow_query!(
+                            self.hooks.slow_query_log,
+                            &stmt,
+                            conn,
+                            crate::driver::sqlx_mysql::sqlx_query,
+                            {
+                                crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                                    query
+                                        .fetch_all(&mut *conn)
+                                        .await
+                                        .map(|rows| rows.into_iter().map(|r| r.into()).collect())
+                                        .map_err(sqlx_error_to_query_err)
+                                })
+                            }
+                        )
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    InnerConnection::Postgres(conn) => {
+                        let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
+                        crate::slow_query!(
+                            self.hooks.slow_query_log,
+                            &stmt,
+                            conn,
+                            crate::driver::sqlx_postgres::sqlx_query,
+                            {
+                                crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                                    query
+                                        .fetch_all(&mut *conn)
+                                        .await
+                                        .map(|rows| rows.into_iter().map(|r| r.into()).collect())
+                                        .map_err(sqlx_error_to_query_err)
+                                })
+                            }
+                        )
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    InnerConnection::Sqlite(conn) => {
+                        let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+                        crate::slow_query!(
+                            self.hooks.slow_query_log,
+                            &stmt,
+                            conn,
+                            crate::driver::sqlx_sqlite::sqlx_query,
+                            {
+                                crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                                    query
+                                        .fetch_all(&mut *conn)
+                                        .await
+                                        .map(|rows| rows.into_iter().map(|r| r.into()).collect())
+                                        .map_err(sqlx_error_to_query_err)
+                                })
+                            }
+                        )
+                    }
+                    #[cfg(feature = "mock")]
+                    InnerConnection::Mock(conn) => {
+                        crate::metric::metric!(self.hooks.metric_callback, &stmt, None, true, {
+                            conn.query_all(stmt.clone())
+                        })
+                    }
+                    #[allow(unreachable_patterns)]
+                    _ => Err(conn_err("Disconnected")),
+                }
+            })
+        })
+        .await
     }
 }
 
@@ -467,14 +742,20 @@ impl StreamTrait for DatabaseTransaction {
         &'a self,
         stmt: Statement,
     ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
-        Box::pin(async move {
-            let conn = self.conn.lock().await;
-            Ok(crate::TransactionStream::build(
-                conn,
-                stmt,
-                self.metric_callback.clone(),
-            ))
-        })
+        Box::pin(interceptor::stream(
+            self.hooks.interceptors.clone(),
+            stmt,
+            move |stmt| async move {
+                crate::tracing_spans::db_span!(&stmt, parent: &self.span, {
+                    let conn = self.conn.lock().await;
+                    Ok(crate::TransactionStream::build(
+                        conn,
+                        stmt,
+                        self.hooks.metric_callback.clone(),
+                    ))
+                })
+            },
+        ))
     }
 }
 
@@ -485,9 +766,10 @@ impl TransactionTrait for DatabaseTransaction {
         DatabaseTransaction::begin(
             Arc::clone(&self.conn),
             self.backend,
-            self.metric_callback.clone(),
+            self.hooks.clone(),
             None,
             None,
+            Some(self),
         )
         .await
     }
@@ -501,9 +783,10 @@ impl TransactionTrait for DatabaseTransaction {
         DatabaseTransaction::begin(
             Arc::clone(&self.conn),
             self.backend,
-            self.metric_callback.clone(),
+            self.hooks.clone(),
             isolation_level,
             access_mode,
+            Some(self),
         )
         .await
     }
@@ -1,6 +1,7 @@
 use crate::{
-    debug_print, error::*, DatabaseConnection, DbBackend, ExecResult, MockDatabase, QueryResult,
-    Statement, Transaction,
+    debug_print, error::*, DatabaseConnection, DbBackend, ExecResult, ExecResultHolder,
+    Interceptors, MockDatabase, MockExecResult, QueryResult, Statement, Transaction,
+    TransactionHooks,
 };
 use futures::Stream;
 use std::{
@@ -8,7 +9,7 @@ use std::{
     pin::Pin,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc, Mutex,
+        Arc, Mutex, PoisonError, RwLock,
     },
 };
 use tracing::instrument;
@@ -18,11 +19,23 @@ use tracing::instrument;
 pub struct MockDatabaseConnector;
 
 /// Defines a connection for the [MockDatabase]
-#[derive(Debug)]
 pub struct MockDatabaseConnection {
     execute_counter: AtomicUsize,
     query_counter: AtomicUsize,
     mocker: Mutex<Box<dyn MockDatabaseTrait>>,
+    pub(crate) metric_callback: Option<crate::metric::Callback>,
+    pub(crate) interceptors: RwLock<Interceptors>,
+}
+
+impl Debug for MockDatabaseConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockDatabaseConnection")
+            .field("execute_counter", &self.execute_counter)
+            .field("query_counter", &self.query_counter)
+            .field("mocker", &self.mocker)
+            .field("interceptors", &self.interceptors)
+            .finish()
+    }
 }
 
 /// A Trait for any type wanting to perform operations on the [MockDatabase]
@@ -42,6 +55,10 @@ pub trait MockDatabaseTrait: Send + Debug {
     /// Roll back a transaction since errors were encountered
     fn rollback(&mut self);
 
+    /// Create, release or roll back to a named savepoint in the open transaction.
+    /// Does nothing by default, so that existing implementations keep compiling.
+    fn savepoint(&mut self, _stmt: Statement) {}
+
     /// Get all logs from a [MockDatabase] and return a [Transaction]
     fn drain_transaction_log(&mut self) -> Vec<Transaction>;
 
@@ -106,9 +123,34 @@ impl MockDatabaseConnection {
             execute_counter: AtomicUsize::new(0),
             query_counter: AtomicUsize::new(0),
             mocker: Mutex::new(Box::new(m)),
+            metric_callback: None,
+            interceptors: RwLock::new(Vec::new()),
+        }
+    }
+
+    pub(crate) fn transaction_hooks(&self) -> TransactionHooks {
+        TransactionHooks {
+            metric_callback: self.metric_callback.clone(),
+            interceptors: self.interceptors(),
+            slow_query_log: None,
         }
     }
 
+    pub(crate) fn interceptors(&self) -> Interceptors {
+        // pushing an interceptor can't leave the stack half updated
+        self.interceptors
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub(crate) fn add_interceptor(&self, interceptor: Arc<dyn crate::Interceptor>) {
+        self.interceptors
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(interceptor)
+    }
+
     pub(crate) fn get_mocker_mutex(&self) -> &Mutex<Box<dyn MockDatabaseTrait>> {
         &self.mocker
     }
@@ -194,4 +236,14 @@ impl MockDatabaseConnection {
             .expect("Failed to acquire mocker")
             .rollback()
     }
+
+    /// Create, release or roll back to a named savepoint
+    #[instrument(level = "trace")]
+    pub fn savepoint(&self, statement: Statement) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", statement);
+        self.mocker.lock().map_err(exec_err)?.savepoint(statement);
+        Ok(ExecResult {
+            result: ExecResultHolder::Mock(MockExecResult::default()),
+        })
+    }
 }
@@ -1,5 +1,5 @@
 use sea_query::Values;
-use std::{future::Future, pin::Pin, sync::Arc};
+use std::{future::Future, pin::Pin, sync::Arc, time::Instant};
 
 use sqlx::{
     mysql::{MySqlConnectOptions, MySqlQueryResult, MySqlRow},
@@ -12,7 +12,8 @@ use tracing::instrument;
 
 use crate::{
     debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
-    DatabaseTransaction, DbBackend, IsolationLevel, QueryStream, Statement, TransactionError,
+    DatabaseTransaction, DbBackend, Interceptors, IsolationLevel, QueryStream, SlowQueryLog,
+    Statement, TransactionError, TransactionHooks,
 };
 
 use super::sqlx_common::*;
@@ -26,6 +27,8 @@ pub struct SqlxMySqlConnector;
 pub struct SqlxMySqlPoolConnection {
     pub(crate) pool: MySqlPool,
     metric_callback: Option<crate::metric::Callback>,
+    pub(crate) interceptors: Interceptors,
+    slow_query_log: Option<SlowQueryLog>,
 }
 
 impl std::fmt::Debug for SqlxMySqlPoolConnection {
@@ -53,11 +56,14 @@ impl SqlxMySqlConnector {
         } else {
             opt.log_statements(options.sqlx_logging_level);
         }
+        let slow_query_log = options.get_slow_query_log();
         match options.pool_options().connect_with(opt).await {
             Ok(pool) => Ok(DatabaseConnection::SqlxMySqlPoolConnection(
                 SqlxMySqlPoolConnection {
                     pool,
                     metric_callback: None,
+                    interceptors: Vec::new(),
+                    slow_query_log,
                 },
             )),
             Err(e) => Err(sqlx_error_to_conn_err(e)),
@@ -71,6 +77,8 @@ impl SqlxMySqlConnector {
         DatabaseConnection::SqlxMySqlPoolConnection(SqlxMySqlPoolConnection {
             pool,
             metric_callback: None,
+            interceptors: Vec::new(),
+            slow_query_log: None,
         })
     }
 }
@@ -81,17 +89,23 @@ impl SqlxMySqlPoolConnection {
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            crate::metric::metric!(self.metric_callback, &stmt, {
-                match query.execute(conn).await {
-                    Ok(res) => Ok(res.into()),
-                    Err(err) => Err(sqlx_error_to_exec_err(err)),
-                }
-            })
-        } else {
-            Err(DbErr::ConnectionAcquire)
-        }
+        crate::tracing_spans::db_span!(&stmt, {
+            let query = sqlx_query(&stmt);
+            let acquire_start = Instant::now();
+            if let Ok(conn) = &mut self.pool.acquire().await {
+                let pool_wait = Some(acquire_start.elapsed());
+                crate::slow_query!(self.slow_query_log, &stmt, conn, sqlx_query, {
+                    crate::metric::metric!(self.metric_callback, &stmt, pool_wait, false, {
+                        match query.execute(&mut *conn).await {
+                            Ok(res) => Ok(res.into()),
+                            Err(err) => Err(sqlx_error_to_exec_err(err)),
+                        }
+                    })
+                })
+            } else {
+                Err(DbErr::ConnectionAcquire)
+            }
+        })
     }
 
     /// Execute an unprepared SQL statement on a MySQL backend
@@ -99,14 +113,26 @@ impl SqlxMySqlPoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            match conn.execute(sql).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
+        crate::tracing_spans::db_span!(DbBackend::MySql, &sql, {
+            let acquire_start = Instant::now();
+            if let Ok(conn) = &mut self.pool.acquire().await {
+                let pool_wait = Some(acquire_start.elapsed());
+                crate::metric::metric!(
+                    self.metric_callback,
+                    &Statement::from_string(DbBackend::MySql, sql.to_owned()),
+                    pool_wait,
+                    false,
+                    {
+                        match conn.execute(sql).await {
+                            Ok(res) => Ok(res.into()),
+                            Err(err) => Err(sqlx_error_to_exec_err(err)),
+                        }
+                    }
+                )
+            } else {
+                Err(DbErr::ConnectionAcquire)
             }
-        } else {
-            Err(DbErr::ConnectionAcquire)
-        }
+        })
     }
 
     /// Get one result from a SQL query. Returns [Option::None] if no match was found
@@ -114,20 +140,26 @@ impl SqlxMySqlPoolConnection {
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            crate::metric::metric!(self.metric_callback, &stmt, {
-                match query.fetch_one(conn).await {
-                    Ok(row) => Ok(Some(row.into())),
-                    Err(err) => match err {
-                        sqlx::Error::RowNotFound => Ok(None),
-                        _ => Err(sqlx_error_to_query_err(err)),
-                    },
-                }
-            })
-        } else {
-            Err(DbErr::ConnectionAcquire)
-        }
+        crate::tracing_spans::db_span!(&stmt, {
+            let query = sqlx_query(&stmt);
+            let acquire_start = Instant::now();
+            if let Ok(conn) = &mut self.pool.acquire().await {
+                let pool_wait = Some(acquire_start.elapsed());
+                crate::slow_query!(self.slow_query_log, &stmt, conn, sqlx_query, {
+                    crate::metric::metric!(self.metric_callback, &stmt, pool_wait, false, {
+                        match query.fetch_one(&mut *conn).await {
+                            Ok(row) => Ok(Some(row.into())),
+                            Err(err) => match err {
+                                sqlx::Error::RowNotFound => Ok(None),
+                                _ => Err(sqlx_error_to_query_err(err)),
+                            },
+                        }
+                    })
+                })
+            } else {
+                Err(DbErr::ConnectionAcquire)
+            }
+        })
     }
 
     /// Get the results of a query returning them as a Vec<[QueryResult]>
@@ -135,17 +167,23 @@ impl SqlxMySqlPoolConnection {
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            crate::metric::metric!(self.metric_callback, &stmt, {
-                match query.fetch_all(conn).await {
-                    Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                    Err(err) => Err(sqlx_error_to_query_err(err)),
-                }
-            })
-        } else {
-            Err(DbErr::ConnectionAcquire)
-        }
+        crate::tracing_spans::db_span!(&stmt, {
+            let query = sqlx_query(&stmt);
+            let acquire_start = Instant::now();
+            if let Ok(conn) = &mut self.pool.acquire().await {
+                let pool_wait = Some(acquire_start.elapsed());
+                crate::slow_query!(self.slow_query_log, &stmt, conn, sqlx_query, {
+                    crate::metric::metric!(self.metric_callback, &stmt, pool_wait, false, {
+                        match query.fetch_all(&mut *conn).await {
+                            Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+                            Err(err) => Err(sqlx_error_to_query_err(err)),
+                        }
+                    })
+                })
+            } else {
+                Err(DbErr::ConnectionAcquire)
+            }
+        })
     }
 
     /// Stream the results of executing a SQL query
@@ -153,15 +191,17 @@ impl SqlxMySqlPoolConnection {
     pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
         debug_print!("{}", stmt);
 
-        if let Ok(conn) = self.pool.acquire().await {
-            Ok(QueryStream::from((
-                conn,
-                stmt,
-                self.metric_callback.clone(),
-            )))
-        } else {
-            Err(DbErr::ConnectionAcquire)
-        }
+        crate::tracing_spans::db_span!(&stmt, {
+            if let Ok(conn) = self.pool.acquire().await {
+                Ok(QueryStream::from((
+                    conn,
+                    stmt,
+                    self.metric_callback.clone(),
+                )))
+            } else {
+                Err(DbErr::ConnectionAcquire)
+            }
+        })
     }
 
     /// Bundle a set of SQL statements that execute together.
@@ -174,7 +214,7 @@ impl SqlxMySqlPoolConnection {
         if let Ok(conn) = self.pool.acquire().await {
             DatabaseTransaction::new_mysql(
                 conn,
-                self.metric_callback.clone(),
+                self.transaction_hooks(),
                 isolation_level,
                 access_mode,
             )
@@ -203,7 +243,7 @@ impl SqlxMySqlPoolConnection {
         if let Ok(conn) = self.pool.acquire().await {
             let transaction = DatabaseTransaction::new_mysql(
                 conn,
-                self.metric_callback.clone(),
+                self.transaction_hooks(),
                 isolation_level,
                 access_mode,
             )
@@ -222,6 +262,14 @@ impl SqlxMySqlPoolConnection {
         self.metric_callback = Some(Arc::new(callback));
     }
 
+    fn transaction_hooks(&self) -> TransactionHooks {
+        TransactionHooks {
+            metric_callback: self.metric_callback.clone(),
+            interceptors: self.interceptors.clone(),
+            slow_query_log: self.slow_query_log.clone(),
+        }
+    }
+
     /// Explicitly close the MySQL connection
     pub async fn close(self) -> Result<(), DbErr> {
         self.pool.close().await;
@@ -259,20 +307,16 @@ pub(crate) async fn set_transaction_config(
     access_mode: Option<AccessMode>,
 ) -> Result<(), DbErr> {
     if let Some(isolation_level) = isolation_level {
-        let stmt = Statement {
-            sql: format!("SET TRANSACTION ISOLATION LEVEL {isolation_level}"),
-            values: None,
-            db_backend: DbBackend::MySql,
-        };
+        let stmt = Statement::from_string(
+            DbBackend::MySql,
+            format!("SET TRANSACTION ISOLATION LEVEL {isolation_level}"),
+        );
         let query = sqlx_query(&stmt);
         conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
     }
     if let Some(access_mode) = access_mode {
-        let stmt = Statement {
-            sql: format!("SET TRANSACTION {access_mode}"),
-            values: None,
-            db_backend: DbBackend::MySql,
-        };
+        let stmt =
+            Statement::from_string(DbBackend::MySql, format!("SET TRANSACTION {access_mode}"));
         let query = sqlx_query(&stmt);
         conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
     }
@@ -1,5 +1,5 @@
 use sea_query::Values;
-use std::{future::Future, pin::Pin, sync::Arc};
+use std::{future::Future, pin::Pin, sync::Arc, time::Instant};
 
 use sqlx::{
     pool::PoolConnection,
@@ -12,7 +12,8 @@ use tracing::instrument;
 
 use crate::{
     debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
-    DatabaseTransaction, DbBackend, IsolationLevel, QueryStream, Statement, TransactionError,
+    DatabaseTransaction, DbBackend, Interceptors, IsolationLevel, QueryStream, SlowQueryLog,
+    Statement, TransactionError, TransactionHooks,
 };
 
 use super::sqlx_common::*;
@@ -26,6 +27,8 @@ pub struct SqlxPostgresConnector;
 pub struct SqlxPostgresPoolConnection {
     pub(crate) pool: PgPool,
     metric_callback: Option<crate::metric::Callback>,
+    pub(crate) interceptors: Interceptors,
+    slow_query_log: Option<SlowQueryLog>,
 }
 
 impl std::fmt::Debug for SqlxPostgresPoolConnection {
@@ -57,6 +60,7 @@ impl SqlxPostgresConnector {
             .schema_search_path
             .as_ref()
             .map(|schema| format!("SET search_path = '{schema}'"));
+        let slow_query_log = options.get_slow_query_log();
         let mut pool_options = options.pool_options();
         if let Some(sql) = set_search_path_sql {
             pool_options = pool_options.after_connect(move |conn, _| {
@@ -73,6 +77,8 @@ impl SqlxPostgresConnector {
                 SqlxPostgresPoolConnection {
                     pool,
                     metric_callback: None,
+                    interceptors: Vec::new(),
+                    slow_query_log,
                 },
             )),
             Err(e) => Err(sqlx_error_to_conn_err(e)),
@@ -86,6 +92,8 @@ impl SqlxPostgresConnector {
         DatabaseConnection::SqlxPostgresPoolConnection(SqlxPostgresPoolConnection {
             pool,
             metric_callback: None,
+            interceptors: Vec::new(),
+            slow_query_log: None,
         })
     }
 }
@@ -96,17 +104,23 @@ impl SqlxPostgresPoolConnection {
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            crate::metric::metric!(self.metric_callback, &stmt, {
-                match query.execute(conn).await {
-                    Ok(res) => Ok(res.into()),
-                    Err(err) => Err(sqlx_error_to_exec_err(err)),
-                }
-            })
-        } else {
-            Err(DbErr::ConnectionAcquire)
-        }
+        crate::tracing_spans::db_span!(&stmt, {
+            let query = sqlx_query(&stmt);
+            let acquire_start = Instant::now();
+            if let Ok(conn) = &mut self.pool.acquire().await {
+                let pool_wait = Some(acquire_start.elapsed());
+                crate::slow_query!(self.slow_query_log, &stmt, conn, sqlx_query, {
+                    crate::metric::metric!(self.metric_callback, &stmt, pool_wait, false, {
+                        match query.execute(&mut *conn).await {
+                            Ok(res) => Ok(res.into()),
+                            Err(err) => Err(sqlx_error_to_exec_err(err)),
+                        }
+                    })
+                })
+            } else {
+                Err(DbErr::ConnectionAcquire)
+            }
+        })
     }
 
     /// Execute an unprepared SQL statement on a PostgreSQL backend
@@ -114,14 +128,26 @@ impl SqlxPostgresPoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            match conn.execute(sql).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
+        crate::tracing_spans::db_span!(DbBackend::Postgres, &sql, {
+            let acquire_start = Instant::now();
+            if let Ok(conn) = &mut self.pool.acquire().await {
+                let pool_wait = Some(acquire_start.elapsed());
+                crate::metric::metric!(
+                    self.metric_callback,
+                    &Statement::from_string(DbBackend::Postgres, sql.to_owned()),
+                    pool_wait,
+                    false,
+                    {
+                        match conn.execute(sql).await {
+                            Ok(res) => Ok(res.into()),
+                            Err(err) => Err(sqlx_error_to_exec_err(err)),
+                        }
+                    }
+                )
+            } else {
+                Err(DbErr::ConnectionAcquire)
             }
-        } else {
-            Err(DbErr::ConnectionAcquire)
-        }
+        })
     }
 
     /// Get one result from a SQL query. Returns [Option::None] if no match was found
@@ -129,20 +155,26 @@ impl SqlxPostgresPoolConnection {
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            crate::metric::metric!(self.metric_callback, &stmt, {
-                match query.fetch_one(conn).await {
-                    Ok(row) => Ok(Some(row.into())),
-                    Err(err) => match err {
-                        sqlx::Error::RowNotFound => Ok(None),
-                        _ => Err(sqlx_error_to_query_err(err)),
-                    },
-                }
-            })
-        } else {
-            Err(DbErr::ConnectionAcquire)
-        }
+        crate::tracing_spans::db_span!(&stmt, {
+            let query = sqlx_query(&stmt);
+            let acquire_start = Instant::now();
+            if let Ok(conn) = &mut self.pool.acquire().await {
+                let pool_wait = Some(acquire_start.elapsed());
+                crate::slow_query!(self.slow_query_log, &stmt, conn, sqlx_query, {
+                    crate::metric::metric!(self.metric_callback, &stmt, pool_wait, false, {
+                        match query.fetch_one(&mut *conn).await {
+                            Ok(row) => Ok(Some(row.into())),
+                            Err(err) => match err {
+                                sqlx::Error::RowNotFound => Ok(None),
+                                _ => Err(sqlx_error_to_query_err(err)),
+                            },
+                        }
+                    })
+                })
+            } else {
+                Err(DbErr::ConnectionAcquire)
+            }
+        })
     }
 
     /// Get the results of a query returning them as a Vec<[QueryResult]>
@@ -150,17 +182,23 @@ impl SqlxPostgresPoolConnection {
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            crate::metric::metric!(self.metric_callback, &stmt, {
-                match query.fetch_all(conn).await {
-                    Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                    Err(err) => Err(sqlx_error_to_query_err(err)),
-                }
-            })
-        } else {
-            Err(DbErr::ConnectionAcquire)
-        }
+        crate::tracing_spans::db_span!(&stmt, {
+            let query = sqlx_query(&stmt);
+            let acquire_start = Instant::now();
+            if let Ok(conn) = &mut self.pool.acquire().await {
+                let pool_wait = Some(acquire_start.elapsed());
+                crate::slow_query!(self.slow_query_log, &stmt, conn, sqlx_query, {
+                    crate::metric::metric!(self.metric_callback, &stmt, pool_wait, false, {
+                        match query.fetch_all(&mut *conn).await {
+                            Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+                            Err(err) => Err(sqlx_error_to_query_err(err)),
+                        }
+                    })
+                })
+            } else {
+                Err(DbErr::ConnectionAcquire)
+            }
+        })
     }
 
     /// Stream the results of executing a SQL query
@@ -168,15 +206,17 @@ impl SqlxPostgresPoolConnection {
     pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
         debug_print!("{}", stmt);
 
-        if let Ok(conn) = self.pool.acquire().await {
-            Ok(QueryStream::from((
-                conn,
-                stmt,
-                self.metric_callback.clone(),
-            )))
-        } else {
-            Err(DbErr::ConnectionAcquire)
-        }
+        crate::tracing_spans::db_span!(&stmt, {
+            if let Ok(conn) = self.pool.acquire().await {
+                Ok(QueryStream::from((
+                    conn,
+                    stmt,
+                    self.metric_callback.clone(),
+                )))
+            } else {
+                Err(DbErr::ConnectionAcquire)
+            }
+        })
     }
 
     /// Bundle a set of SQL statements that execute together.
@@ -189,7 +229,7 @@ impl SqlxPostgresPoolConnection {
         if let Ok(conn) = self.pool.acquire().await {
             DatabaseTransaction::new_postgres(
                 conn,
-                self.metric_callback.clone(),
+                self.transaction_hooks(),
                 isolation_level,
                 access_mode,
             )
@@ -218,7 +258,7 @@ impl SqlxPostgresPoolConnection {
         if let Ok(conn) = self.pool.acquire().await {
             let transaction = DatabaseTransaction::new_postgres(
                 conn,
-                self.metric_callback.clone(),
+                self.transaction_hooks(),
                 isolation_level,
                 access_mode,
             )
@@ -237,6 +277,14 @@ impl SqlxPostgresPoolConnection {
         self.metric_callback = Some(Arc::new(callback));
     }
 
+    fn transaction_hooks(&self) -> TransactionHooks {
+        TransactionHooks {
+            metric_callback: self.metric_callback.clone(),
+            interceptors: self.interceptors.clone(),
+            slow_query_log: self.slow_query_log.clone(),
+        }
+    }
+
     /// Explicitly close the Postgres connection
     pub async fn close(self) -> Result<(), DbErr> {
         self.pool.close().await;
@@ -274,20 +322,18 @@ pub(crate) async fn set_transaction_config(
     access_mode: Option<AccessMode>,
 ) -> Result<(), DbErr> {
     if let Some(isolation_level) = isolation_level {
-        let stmt = Statement {
-            sql: format!("SET TRANSACTION ISOLATION LEVEL {isolation_level}"),
-            values: None,
-            db_backend: DbBackend::Postgres,
-        };
+        let stmt = Statement::from_string(
+            DbBackend::Postgres,
+            format!("SET TRANSACTION ISOLATION LEVEL {isolation_level}"),
+        );
         let query = sqlx_query(&stmt);
         conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
     }
     if let Some(access_mode) = access_mode {
-        let stmt = Statement {
-            sql: format!("SET TRANSACTION {access_mode}"),
-            values: None,
-            db_backend: DbBackend::Postgres,
-        };
+        let stmt = Statement::from_string(
+            DbBackend::Postgres,
+            format!("SET TRANSACTION {access_mode}"),
+        );
         let query = sqlx_query(&stmt);
         conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
     }
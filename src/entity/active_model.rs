@@ -1,5 +1,6 @@
 use crate::{
-    error::*, ConnectionTrait, DeleteResult, EntityTrait, Iterable, PrimaryKeyToColumn, Value,
+    error::*, ConnectionTrait, DeleteResult, EntityTrait, Insert, Iterable, PrimaryKeyToColumn,
+    Value,
 };
 use async_trait::async_trait;
 use sea_query::{Nullable, ValueTuple};
@@ -422,6 +423,85 @@ pub trait ActiveModelTrait: Clone + Debug {
         Self::after_save(model, db, false).await
     }
 
+    /// Perform an `INSERT` of the ActiveModel, or update the existing row with the same primary key
+    /// instead, returning the resulting model. See [Insert::upsert](crate::Insert::upsert).
+    ///
+    /// # Example (MySQL)
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::MySql)
+    /// #     .append_exec_results([
+    /// #         MockExecResult {
+    /// #             last_insert_id: 0,
+    /// #             rows_affected: 2,
+    /// #         },
+    /// #     ])
+    /// #     .append_query_results([
+    /// #         [cake::Model {
+    /// #             id: 1,
+    /// #             name: "Apple Pie".to_owned(),
+    /// #         }],
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let apple = cake::ActiveModel {
+    ///     id: Set(1),
+    ///     name: Set("Apple Pie".to_owned()),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     apple.insert_or_update(&db).await?,
+    ///     cake::Model {
+    ///         id: 1,
+    ///         name: "Apple Pie".to_owned(),
+    ///     }
+    /// );
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::MySql,
+    ///             r#"INSERT INTO `cake` (`id`, `name`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)"#,
+    ///             [1.into(), "Apple Pie".into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::MySql,
+    ///             r#"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` IN (?) ORDER BY `cake`.`id` ASC"#,
+    ///             [1.into()]
+    ///         )
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn insert_or_update<'a, C>(
+        self,
+        db: &'a C,
+    ) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
+    where
+        Self: ActiveModelBehavior + 'a,
+        C: ConnectionTrait,
+    {
+        let am = ActiveModelBehavior::before_save(self, db, true).await?;
+        let model = Insert::one(am)
+            .upsert()
+            .exec_with_returning_many(db)
+            .await?
+            .pop()
+            .ok_or_else(|| DbErr::RecordNotFound("Failed to find upserted item".to_owned()))?;
+        Self::after_save(model, db, true).await
+    }
+
     /// Insert the model if primary key is `NotSet`, update otherwise.
     /// Only works if the entity has auto increment primary key.
     async fn save<'a, C>(self, db: &'a C) -> Result<Self, DbErr>
@@ -501,6 +581,19 @@ pub trait ActiveModelTrait: Clone + Debug {
         Ok(delete_res)
     }
 
+    /// Delete an active model by its primary key, even if the Entity has a soft delete column
+    async fn force_delete<'a, C>(self, db: &'a C) -> Result<DeleteResult, DbErr>
+    where
+        Self: ActiveModelBehavior + 'a,
+        C: ConnectionTrait,
+    {
+        let am = ActiveModelBehavior::before_delete(self, db).await?;
+        let am_clone = am.clone();
+        let delete_res = Self::Entity::delete(am).force_delete().exec(db).await?;
+        ActiveModelBehavior::after_delete(am_clone, db).await?;
+        Ok(delete_res)
+    }
+
     /// Set the corresponding attributes in the ActiveModel from a JSON value
     ///
     /// Note that this method will not alter the primary key values in ActiveModel.
@@ -165,10 +165,25 @@ pub trait EntityTrait: EntityName {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// If the Entity has a `#[sea_orm(soft_delete)]` column, soft deleted rows are excluded.
+    /// Use [`EntityTrait::with_deleted`] or [`EntityTrait::only_deleted`] to select them.
     fn find() -> Select<Self> {
+        Select::new().filter_not_deleted()
+    }
+
+    /// Construct select statement to find one / all models, including the soft deleted ones.
+    /// Same as [`EntityTrait::find`] if the Entity has no soft delete column.
+    fn with_deleted() -> Select<Self> {
         Select::new()
     }
 
+    /// Construct select statement to find one / all models that have been soft deleted.
+    /// Same as [`EntityTrait::find`] if the Entity has no soft delete column.
+    fn only_deleted() -> Select<Self> {
+        Select::new().filter_deleted()
+    }
+
     /// Find a model by primary key
     ///
     /// # Example
@@ -467,6 +482,68 @@ pub trait EntityTrait: EntityName {
         Insert::many(models)
     }
 
+    /// Insert many models, updating the existing rows with the same primary key instead,
+    /// see [Insert::upsert]. Use [Insert::upsert_on] to have the conflict target be a unique index.
+    ///
+    /// # Example (Postgres)
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[
+    /// #         cake::Model {
+    /// #             id: 1,
+    /// #             name: "Apple Pie".to_owned(),
+    /// #         },
+    /// #         cake::Model {
+    /// #             id: 2,
+    /// #             name: "Orange Scone".to_owned(),
+    /// #         },
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let apple = cake::ActiveModel {
+    ///     id: Set(1),
+    ///     name: Set("Apple Pie".to_owned()),
+    /// };
+    /// let orange = cake::ActiveModel {
+    ///     id: Set(2),
+    ///     name: Set("Orange Scone".to_owned()),
+    /// };
+    ///
+    /// let cakes = cake::Entity::upsert_many([apple, orange])
+    ///     .exec_with_returning_many(&db)
+    ///     .await?;
+    ///
+    /// assert_eq!(cakes.len(), 2);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2), ($3, $4) ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name" RETURNING "id", "name""#,
+    ///         [1.into(), "Apple Pie".into(), 2.into(), "Orange Scone".into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn upsert_many<A, I>(models: I) -> Insert<A>
+    where
+        A: ActiveModelTrait<Entity = Self>,
+        I: IntoIterator<Item = A>,
+    {
+        Insert::many(models).upsert()
+    }
+
     /// Update an model in database
     ///
     /// - To apply where conditions / filters, see [`QueryFilter`](crate::query::QueryFilter)
@@ -689,6 +766,9 @@ pub trait EntityTrait: EntityName {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// If the Entity has a `#[sea_orm(soft_delete)]` column, the row is marked as deleted
+    /// with an UPDATE instead. See [`DeleteOne::force_delete`] to remove it for real.
     fn delete<A>(model: A) -> DeleteOne<A>
     where
         A: ActiveModelTrait<Entity = Self>,
@@ -745,6 +825,11 @@ pub trait EntityTrait: EntityName {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// If the Entity has a `#[sea_orm(soft_delete)]` column, the rows are marked as deleted
+    /// with an UPDATE instead. See [`DeleteMany::force_delete`] to remove them for real.
+    /// Only the conditions added with `filter` are carried over to the UPDATE, so executing
+    /// a soft delete whose statement has been changed through `query()` returns an error.
     fn delete_many() -> DeleteMany<Self> {
         Delete::many(Self::default())
     }
@@ -965,4 +1050,53 @@ mod tests {
         delete_by_id("UUID");
         delete_by_id(Cow::from("UUID"));
     }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn entity_model_4() {
+        use crate::{entity::*, query::*, DbBackend};
+
+        mod hello {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "hello")]
+            pub struct Model {
+                #[sea_orm(primary_key, auto_increment = false)]
+                pub tenant_id: i32,
+                #[sea_orm(primary_key, auto_increment = false)]
+                pub region: String,
+                #[sea_orm(primary_key, auto_increment = false)]
+                pub partition: i16,
+                #[sea_orm(primary_key, auto_increment = false)]
+                pub id: i64,
+                pub name: String,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(
+            hello::Entity::find_by_id((1, "eu".to_owned(), 2, 3))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "hello"."tenant_id", "hello"."region", "hello"."partition", "hello"."id", "hello"."name""#,
+                r#"FROM "hello""#,
+                r#"WHERE "hello"."tenant_id" = 1 AND "hello"."region" = 'eu' AND "hello"."partition" = 2 AND "hello"."id" = 3"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            hello::Entity::delete_by_id((1, "eu".to_owned(), 2, 3))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"DELETE FROM "hello" WHERE "hello"."tenant_id" = 1 AND "hello"."region" = 'eu' AND "hello"."partition" = 2 AND "hello"."id" = 3"#
+        );
+    }
 }
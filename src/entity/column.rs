@@ -16,6 +16,10 @@ pub struct ColumnDef {
     pub(crate) unique: bool,
     pub(crate) indexed: bool,
     pub(crate) default_value: Option<Value>,
+    pub(crate) version: bool,
+    pub(crate) soft_delete: bool,
+    pub(crate) created_at: bool,
+    pub(crate) updated_at: bool,
 }
 
 macro_rules! bind_oper {
@@ -277,6 +281,71 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
             col.as_enum(type_name)
         })
     }
+
+    /// The current timestamp to fill into a `created_at` or `updated_at` column,
+    /// in the Rust type of the column. `None` for any other column.
+    fn auto_timestamp(&self) -> Option<Value> {
+        None
+    }
+}
+
+/// A date time type that can be filled with the current timestamp,
+/// used by `#[sea_orm(created_at)]` and `#[sea_orm(updated_at)]` columns
+pub trait CurrentTimestamp {
+    /// The current timestamp
+    fn current_timestamp() -> Self;
+}
+
+impl<T> CurrentTimestamp for Option<T>
+where
+    T: CurrentTimestamp,
+{
+    fn current_timestamp() -> Self {
+        Some(T::current_timestamp())
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CurrentTimestamp for chrono::NaiveDateTime {
+    fn current_timestamp() -> Self {
+        chrono::Utc::now().naive_utc()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CurrentTimestamp for chrono::DateTime<chrono::Utc> {
+    fn current_timestamp() -> Self {
+        chrono::Utc::now()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CurrentTimestamp for chrono::DateTime<chrono::Local> {
+    fn current_timestamp() -> Self {
+        chrono::Local::now()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CurrentTimestamp for chrono::DateTime<chrono::FixedOffset> {
+    fn current_timestamp() -> Self {
+        chrono::Utc::now().into()
+    }
+}
+
+#[cfg(feature = "with-time")]
+impl CurrentTimestamp for time::PrimitiveDateTime {
+    fn current_timestamp() -> Self {
+        let now = time::OffsetDateTime::now_utc();
+        time::PrimitiveDateTime::new(now.date(), now.time())
+    }
+}
+
+#[cfg(feature = "with-time")]
+impl CurrentTimestamp for time::OffsetDateTime {
+    fn current_timestamp() -> Self {
+        time::OffsetDateTime::now_utc()
+    }
 }
 
 /// SeaORM's utility methods that act on [ColumnType]
@@ -296,6 +365,10 @@ impl ColumnTypeTrait for ColumnType {
             unique: false,
             indexed: false,
             default_value: None,
+            version: false,
+            soft_delete: false,
+            created_at: false,
+            updated_at: false,
         }
     }
 
@@ -344,6 +417,30 @@ impl ColumnDef {
         self
     }
 
+    /// Mark the column as the version counter used for optimistic locking
+    pub fn version(mut self) -> Self {
+        self.version = true;
+        self
+    }
+
+    /// Mark the column as the soft delete marker, either a boolean flag or a nullable timestamp
+    pub fn soft_delete(mut self) -> Self {
+        self.soft_delete = true;
+        self
+    }
+
+    /// Mark the column to be filled with the current timestamp on insert
+    pub fn created_at(mut self) -> Self {
+        self.created_at = true;
+        self
+    }
+
+    /// Mark the column to be filled with the current timestamp on insert and update
+    pub fn updated_at(mut self) -> Self {
+        self.updated_at = true;
+        self
+    }
+
     /// Get [ColumnType] as reference
     pub fn get_column_type(&self) -> &ColumnType {
         &self.col_type
@@ -353,6 +450,26 @@ impl ColumnDef {
     pub fn is_null(&self) -> bool {
         self.null
     }
+
+    /// Returns true if the column is the version counter used for optimistic locking
+    pub fn is_version(&self) -> bool {
+        self.version
+    }
+
+    /// Returns true if the column is the soft delete marker
+    pub fn is_soft_delete(&self) -> bool {
+        self.soft_delete
+    }
+
+    /// Returns true if the column is filled with the current timestamp on insert
+    pub fn is_created_at(&self) -> bool {
+        self.created_at
+    }
+
+    /// Returns true if the column is filled with the current timestamp on insert and update
+    pub fn is_updated_at(&self) -> bool {
+        self.updated_at
+    }
 }
 
 #[derive(Iden)]
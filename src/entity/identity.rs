@@ -11,6 +11,69 @@ pub enum Identity {
     Binary(DynIden, DynIden),
     /// Performs three operations
     Ternary(DynIden, DynIden, DynIden),
+    /// Performs four or more operations, as many as the columns of a composite primary key
+    /// or foreign key, which can have at most six
+    Many(Vec<DynIden>),
+}
+
+impl Identity {
+    /// Get the number of columns in this identity
+    pub fn arity(&self) -> usize {
+        match self {
+            Self::Unary(_) => 1,
+            Self::Binary(_, _) => 2,
+            Self::Ternary(_, _, _) => 3,
+            Self::Many(vec) => vec.len(),
+        }
+    }
+
+    /// Iterate the columns of this identity in order
+    pub fn iter(&self) -> BorrowedIdentityIter<'_> {
+        BorrowedIdentityIter {
+            identity: self,
+            index: 0,
+        }
+    }
+}
+
+impl IntoIterator for Identity {
+    type Item = DynIden;
+    type IntoIter = std::vec::IntoIter<DynIden>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Identity::Unary(iden1) => vec![iden1].into_iter(),
+            Identity::Binary(iden1, iden2) => vec![iden1, iden2].into_iter(),
+            Identity::Ternary(iden1, iden2, iden3) => vec![iden1, iden2, iden3].into_iter(),
+            Identity::Many(vec) => vec.into_iter(),
+        }
+    }
+}
+
+/// Iterator over the columns of a borrowed [Identity]
+#[derive(Debug)]
+pub struct BorrowedIdentityIter<'a> {
+    identity: &'a Identity,
+    index: usize,
+}
+
+impl<'a> Iterator for BorrowedIdentityIter<'a> {
+    type Item = &'a DynIden;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = match (self.identity, self.index) {
+            (Identity::Unary(iden1), 0) => Some(iden1),
+            (Identity::Binary(iden1, _), 0) => Some(iden1),
+            (Identity::Binary(_, iden2), 1) => Some(iden2),
+            (Identity::Ternary(iden1, _, _), 0) => Some(iden1),
+            (Identity::Ternary(_, iden2, _), 1) => Some(iden2),
+            (Identity::Ternary(_, _, iden3), 2) => Some(iden3),
+            (Identity::Many(vec), index) => vec.get(index),
+            _ => None,
+        };
+        self.index += 1;
+        result
+    }
 }
 
 impl Iden for Identity {
@@ -28,6 +91,11 @@ impl Iden for Identity {
                 write!(s, "{}", iden2.to_string()).unwrap();
                 write!(s, "{}", iden3.to_string()).unwrap();
             }
+            Identity::Many(vec) => {
+                for iden in vec.iter() {
+                    write!(s, "{}", iden.to_string()).unwrap();
+                }
+            }
         }
     }
 }
@@ -89,6 +157,26 @@ where
     }
 }
 
+macro_rules! impl_into_identity {
+    ( $($T:ident : $N:tt),+ $(,)? ) => {
+        impl< $($T),+ > IntoIdentity for ( $($T),+ )
+        where
+            $($T: IdenStatic),+
+        {
+            fn into_identity(self) -> Identity {
+                Identity::Many(vec![
+                    $(self.$N.into_iden()),+
+                ])
+            }
+        }
+    };
+}
+
+// impl IntoIdentity for tuples with four or more columns, up to the maximum arity of ValueTuple
+impl_into_identity!(T0:0, T1:1, T2:2, T3:3);
+impl_into_identity!(T0:0, T1:1, T2:2, T3:3, T4:4);
+impl_into_identity!(T0:0, T1:1, T2:2, T3:3, T4:4, T5:5);
+
 impl<E, C> IdentityOf<E> for C
 where
     E: EntityTrait<Column = C>,
@@ -118,3 +206,21 @@ where
         self.into_identity()
     }
 }
+
+macro_rules! impl_identity_of {
+    ( $($T:ident),+ $(,)? ) => {
+        impl<E, C> IdentityOf<E> for ( $($T),+ )
+        where
+            E: EntityTrait<Column = C>,
+            C: ColumnTrait,
+        {
+            fn identity_of(self) -> Identity {
+                self.into_identity()
+            }
+        }
+    };
+}
+
+impl_identity_of!(C, C, C, C);
+impl_identity_of!(C, C, C, C, C);
+impl_identity_of!(C, C, C, C, C, C);
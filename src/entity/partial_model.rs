@@ -0,0 +1,8 @@
+use crate::{FromQueryResult, QuerySelect};
+
+/// A Trait for a struct holding a subset of the columns of a Model,
+/// usually derived with [DerivePartialModel](crate::DerivePartialModel)
+pub trait PartialModelTrait: FromQueryResult {
+    /// Select the columns of the partial Model, aliased to the names of its fields
+    fn select_cols<S: QuerySelect>(select: S) -> S;
+}
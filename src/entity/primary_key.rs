@@ -38,7 +38,9 @@ use std::fmt::Debug;
 /// ```
 /// See module level docs [crate::entity] for a full example
 pub trait PrimaryKeyTrait: IdenStatic + Iterable {
-    #[allow(missing_docs)]
+    /// The value of the Primary Key, a tuple for a composite key.
+    /// As it has to convert from and into a [ValueTuple](sea_query::ValueTuple),
+    /// a composite key can have at most six columns.
     type ValueType: Sized
         + Send
         + Debug
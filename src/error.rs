@@ -73,6 +73,18 @@ pub enum DbErr {
     /// May be the table is empty or the record does not exist
     #[error("None of the records are updated")]
     RecordNotUpdated,
+    /// The version column of the record no longer matches the one held by the ActiveModel,
+    /// that means the record has been updated or deleted by someone else in the meantime
+    #[error("The record has been modified concurrently: version mismatch")]
+    RecordVersionMismatch,
+    /// The operation is not supported by the database backend
+    #[error("{ctx} is not supported by {db}")]
+    BackendNotSupported {
+        /// Database backend
+        db: &'static str,
+        /// Context
+        ctx: &'static str,
+    },
 }
 
 /// Runtime error
@@ -95,6 +107,246 @@ impl PartialEq for DbErr {
 
 impl Eq for DbErr {}
 
+/// A backend independent classification of the errors raised by the database,
+/// see [DbErr::sql_err]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SqlErr {
+    /// A unique constraint or primary key is violated
+    #[error("Unique constraint violated: {message}")]
+    UniqueConstraintViolation {
+        /// The table the constraint belongs to, if reported by the database
+        table: Option<String>,
+        /// The name of the constraint or index, if reported by the database
+        constraint: Option<String>,
+        /// The error message from the database
+        message: String,
+    },
+    /// A foreign key constraint is violated
+    #[error("Foreign key constraint violated: {message}")]
+    ForeignKeyConstraintViolation {
+        /// The table the constraint belongs to, if reported by the database
+        table: Option<String>,
+        /// The name of the constraint, if reported by the database
+        constraint: Option<String>,
+        /// The error message from the database
+        message: String,
+    },
+    /// A `NULL` is written to a `NOT NULL` column
+    #[error("Not null constraint violated: {message}")]
+    NotNullViolation {
+        /// The table of the column, if reported by the database
+        table: Option<String>,
+        /// The name of the column, if reported by the database
+        column: Option<String>,
+        /// The error message from the database
+        message: String,
+    },
+    /// A check constraint is violated
+    #[error("Check constraint violated: {message}")]
+    CheckViolation {
+        /// The table the constraint belongs to, if reported by the database
+        table: Option<String>,
+        /// The name of the constraint, if reported by the database
+        constraint: Option<String>,
+        /// The error message from the database
+        message: String,
+    },
+    /// The transaction is aborted to break a deadlock
+    #[error("Deadlock detected: {message}")]
+    Deadlock {
+        /// The error message from the database
+        message: String,
+    },
+    /// The transaction can't be serialized with concurrent transactions
+    #[error("Serialization failure: {message}")]
+    SerializationFailure {
+        /// The error message from the database
+        message: String,
+    },
+    /// A lock couldn't be acquired in time, or the database is busy
+    #[error("Lock timeout: {message}")]
+    LockTimeout {
+        /// The error message from the database
+        message: String,
+    },
+}
+
+impl DbErr {
+    /// Classify the error raised by the database, `None` if it isn't one of the [SqlErr]s
+    /// or if it doesn't come from the database at all
+    pub fn sql_err(&self) -> Option<SqlErr> {
+        #[cfg(feature = "sqlx-dep")]
+        if let DbErr::Conn(RuntimeErr::SqlxError(sqlx::Error::Database(e)))
+        | DbErr::Exec(RuntimeErr::SqlxError(sqlx::Error::Database(e)))
+        | DbErr::Query(RuntimeErr::SqlxError(sqlx::Error::Database(e))) = self
+        {
+            #[cfg(feature = "sqlx-postgres")]
+            if let Some(e) = e.try_downcast_ref::<sqlx::postgres::PgDatabaseError>() {
+                return SqlErr::from_postgres(
+                    e.code(),
+                    e.table(),
+                    e.constraint(),
+                    e.column(),
+                    e.message(),
+                );
+            }
+            #[cfg(feature = "sqlx-mysql")]
+            if let Some(e) = e.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>() {
+                return SqlErr::from_mysql(e.number(), sqlx::error::DatabaseError::message(e));
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            if let Some(e) = e.try_downcast_ref::<sqlx::sqlite::SqliteError>() {
+                let code = sqlx::error::DatabaseError::code(e)?.parse().ok()?;
+                return SqlErr::from_sqlite(code, sqlx::error::DatabaseError::message(e));
+            }
+        }
+        None
+    }
+}
+
+impl SqlErr {
+    /// Classify by the SQLSTATE, along with the fields of the error response
+    #[allow(dead_code)]
+    pub(crate) fn from_postgres(
+        code: &str,
+        table: Option<&str>,
+        constraint: Option<&str>,
+        column: Option<&str>,
+        message: &str,
+    ) -> Option<Self> {
+        let table = table.map(ToOwned::to_owned);
+        let constraint = constraint.map(ToOwned::to_owned);
+        let message = message.to_owned();
+        Some(match code {
+            "23505" => Self::UniqueConstraintViolation {
+                table,
+                constraint,
+                message,
+            },
+            "23503" => Self::ForeignKeyConstraintViolation {
+                table,
+                constraint,
+                message,
+            },
+            "23502" => Self::NotNullViolation {
+                table,
+                column: column.map(ToOwned::to_owned),
+                message,
+            },
+            "23514" => Self::CheckViolation {
+                table,
+                constraint,
+                message,
+            },
+            "40P01" => Self::Deadlock { message },
+            "40001" => Self::SerializationFailure { message },
+            "55P03" => Self::LockTimeout { message },
+            _ => return None,
+        })
+    }
+
+    /// Classify by the error number, the names are parsed from the message
+    #[allow(dead_code)]
+    pub(crate) fn from_mysql(number: u16, message: &str) -> Option<Self> {
+        // e.g. "Duplicate entry '1' for key 'cake.PRIMARY'", older versions omit the table
+        let quoted = |after: &str| {
+            let (_, rest) = message.split_once(after)?;
+            let end = rest.find(['\'', '`'])?;
+            Some(rest[..end].to_owned())
+        };
+        let message_owned = message.to_owned();
+        Some(match number {
+            1062 | 1586 => {
+                let key = message
+                    .rsplit_once(" for key '")
+                    .and_then(|(_, key)| key.strip_suffix('\''));
+                let (table, constraint) = match key.map(|key| key.rsplit_once('.')) {
+                    Some(Some((table, key))) => (Some(table.to_owned()), Some(key.to_owned())),
+                    Some(None) => (None, key.map(ToOwned::to_owned)),
+                    None => (None, None),
+                };
+                Self::UniqueConstraintViolation {
+                    table,
+                    constraint,
+                    message: message_owned,
+                }
+            }
+            // e.g. "Cannot add or update a child row: a foreign key constraint fails
+            // (`db`.`fruit`, CONSTRAINT `fk-fruit-cake` FOREIGN KEY (`cake_id`) REFERENCES ..."
+            1216 | 1217 | 1451 | 1452 => {
+                let table = message
+                    .split_once(" fails (")
+                    .and_then(|(_, rest)| rest.split_once(", CONSTRAINT"))
+                    .and_then(|(table, _)| table.rsplit('.').next())
+                    .map(|table| table.trim_matches('`').to_owned());
+                Self::ForeignKeyConstraintViolation {
+                    table,
+                    constraint: quoted("CONSTRAINT `"),
+                    message: message_owned,
+                }
+            }
+            // e.g. "Column 'name' cannot be null"
+            1048 => Self::NotNullViolation {
+                table: None,
+                column: quoted("Column '"),
+                message: message_owned,
+            },
+            // e.g. "Check constraint 'cake_chk_1' is violated."
+            3819 => Self::CheckViolation {
+                table: None,
+                constraint: quoted("constraint '"),
+                message: message_owned,
+            },
+            1213 => Self::Deadlock {
+                message: message_owned,
+            },
+            1205 => Self::LockTimeout {
+                message: message_owned,
+            },
+            _ => return None,
+        })
+    }
+
+    /// Classify by the extended result code, the names are parsed from the message
+    #[allow(dead_code)]
+    pub(crate) fn from_sqlite(code: i32, message: &str) -> Option<Self> {
+        // e.g. "UNIQUE constraint failed: cake.name, cake.id" or "CHECK constraint failed: name"
+        let detail = message.split_once("constraint failed: ").map(|(_, d)| d);
+        let column = detail.and_then(|d| d.split(", ").next()?.split_once('.'));
+        let table = column.map(|(table, _)| table.to_owned());
+        let message = message.to_owned();
+        Some(match code {
+            // SQLITE_CONSTRAINT_UNIQUE, SQLITE_CONSTRAINT_PRIMARYKEY
+            2067 | 1555 => Self::UniqueConstraintViolation {
+                table,
+                constraint: None,
+                message,
+            },
+            // SQLITE_CONSTRAINT_FOREIGNKEY
+            787 => Self::ForeignKeyConstraintViolation {
+                table,
+                constraint: None,
+                message,
+            },
+            // SQLITE_CONSTRAINT_NOTNULL
+            1299 => Self::NotNullViolation {
+                table,
+                column: column.map(|(_, column)| column.to_owned()),
+                message,
+            },
+            // SQLITE_CONSTRAINT_CHECK
+            275 => Self::CheckViolation {
+                table: None,
+                constraint: detail.map(ToOwned::to_owned),
+                message,
+            },
+            // SQLITE_BUSY and its extended codes
+            code if code & 0xff == 5 => Self::LockTimeout { message },
+            _ => return None,
+        })
+    }
+}
+
 /// Error during `impl FromStr for Entity::Column`
 #[derive(Error, Debug)]
 #[error("Failed to match \"{0}\" as Column")]
@@ -139,3 +391,124 @@ where
 {
     DbErr::Json(s.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SqlErr;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn sql_err_postgres() {
+        assert_eq!(
+            SqlErr::from_postgres(
+                "23505",
+                Some("cake"),
+                Some("cake_name_key"),
+                None,
+                "duplicate key value violates unique constraint \"cake_name_key\"",
+            ),
+            Some(SqlErr::UniqueConstraintViolation {
+                table: Some("cake".to_owned()),
+                constraint: Some("cake_name_key".to_owned()),
+                message: "duplicate key value violates unique constraint \"cake_name_key\""
+                    .to_owned(),
+            })
+        );
+        assert_eq!(
+            SqlErr::from_postgres("40P01", None, None, None, "deadlock detected"),
+            Some(SqlErr::Deadlock {
+                message: "deadlock detected".to_owned()
+            })
+        );
+        assert_eq!(
+            SqlErr::from_postgres("42P01", None, None, None, "relation does not exist"),
+            None
+        );
+    }
+
+    #[test]
+    fn sql_err_mysql() {
+        assert_eq!(
+            SqlErr::from_mysql(1062, "Duplicate entry 'Apple' for key 'cake.name'"),
+            Some(SqlErr::UniqueConstraintViolation {
+                table: Some("cake".to_owned()),
+                constraint: Some("name".to_owned()),
+                message: "Duplicate entry 'Apple' for key 'cake.name'".to_owned(),
+            })
+        );
+        assert_eq!(
+            SqlErr::from_mysql(1062, "Duplicate entry '1' for key 'PRIMARY'"),
+            Some(SqlErr::UniqueConstraintViolation {
+                table: None,
+                constraint: Some("PRIMARY".to_owned()),
+                message: "Duplicate entry '1' for key 'PRIMARY'".to_owned(),
+            })
+        );
+        let message = "Cannot add or update a child row: a foreign key constraint fails (`bakery`.`fruit`, CONSTRAINT `fk-fruit-cake` FOREIGN KEY (`cake_id`) REFERENCES `cake` (`id`))";
+        assert_eq!(
+            SqlErr::from_mysql(1452, message),
+            Some(SqlErr::ForeignKeyConstraintViolation {
+                table: Some("fruit".to_owned()),
+                constraint: Some("fk-fruit-cake".to_owned()),
+                message: message.to_owned(),
+            })
+        );
+        assert_eq!(
+            SqlErr::from_mysql(1048, "Column 'name' cannot be null"),
+            Some(SqlErr::NotNullViolation {
+                table: None,
+                column: Some("name".to_owned()),
+                message: "Column 'name' cannot be null".to_owned(),
+            })
+        );
+        assert_eq!(
+            SqlErr::from_mysql(3819, "Check constraint 'cake_chk_1' is violated."),
+            Some(SqlErr::CheckViolation {
+                table: None,
+                constraint: Some("cake_chk_1".to_owned()),
+                message: "Check constraint 'cake_chk_1' is violated.".to_owned(),
+            })
+        );
+        assert_eq!(
+            SqlErr::from_mysql(1205, "Lock wait timeout exceeded"),
+            Some(SqlErr::LockTimeout {
+                message: "Lock wait timeout exceeded".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn sql_err_sqlite() {
+        assert_eq!(
+            SqlErr::from_sqlite(2067, "UNIQUE constraint failed: cake.name"),
+            Some(SqlErr::UniqueConstraintViolation {
+                table: Some("cake".to_owned()),
+                constraint: None,
+                message: "UNIQUE constraint failed: cake.name".to_owned(),
+            })
+        );
+        assert_eq!(
+            SqlErr::from_sqlite(1299, "NOT NULL constraint failed: cake.name"),
+            Some(SqlErr::NotNullViolation {
+                table: Some("cake".to_owned()),
+                column: Some("name".to_owned()),
+                message: "NOT NULL constraint failed: cake.name".to_owned(),
+            })
+        );
+        assert_eq!(
+            SqlErr::from_sqlite(275, "CHECK constraint failed: positive_price"),
+            Some(SqlErr::CheckViolation {
+                table: None,
+                constraint: Some("positive_price".to_owned()),
+                message: "CHECK constraint failed: positive_price".to_owned(),
+            })
+        );
+        assert_eq!(
+            SqlErr::from_sqlite(517, "database is locked"),
+            Some(SqlErr::LockTimeout {
+                message: "database is locked".to_owned()
+            })
+        );
+        assert_eq!(SqlErr::from_sqlite(1, "SQL logic error"), None);
+    }
+}
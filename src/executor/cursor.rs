@@ -1,12 +1,12 @@
 use crate::{
-    ConnectionTrait, DbErr, EntityTrait, FromQueryResult, Identity, IntoIdentity, QueryOrder,
-    Select, SelectModel, SelectorTrait,
+    ConnectionTrait, DbErr, EntityTrait, FromQueryResult, IdenStatic, Identity, IntoIdentity,
+    Iterable, ModelTrait, QueryOrder, Select, SelectModel, SelectorTrait,
 };
 use sea_query::{
-    Condition, DynIden, Expr, IntoValueTuple, Order, OrderedStatement, SeaRc, SelectStatement,
-    SimpleExpr, Value, ValueTuple,
+    Condition, DynIden, Expr, IntoValueTuple, NullOrdering, Order, OrderedStatement, SeaRc,
+    SelectStatement, SimpleExpr, Value,
 };
-use std::marker::PhantomData;
+use std::{fmt, marker::PhantomData, str::FromStr};
 
 #[cfg(feature = "with-json")]
 use crate::JsonValue;
@@ -20,10 +20,81 @@ where
     pub(crate) query: SelectStatement,
     pub(crate) table: DynIden,
     pub(crate) order_columns: Identity,
+    pub(crate) orders: Vec<CursorOrder>,
+    pub(crate) num_rows: Option<u64>,
+    pub(crate) before: Option<Vec<Value>>,
+    pub(crate) after: Option<Vec<Value>>,
     pub(crate) last: bool,
     pub(crate) phantom: PhantomData<S>,
 }
 
+/// How a [Cursor] orders one of its columns
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorOrder {
+    /// The direction of the column
+    pub order: Order,
+    /// Where NULL values are placed. If not set, they are placed where the database
+    /// does by default and the column is assumed to have no NULL value.
+    pub nulls: Option<NullOrdering>,
+}
+
+/// A page of results fetched with [Cursor::page]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPage<T> {
+    /// The items of the page, in the order of the cursor
+    pub items: Vec<T>,
+    /// Whether there are more items after the page. Paginating with `last`, this is only
+    /// known to be true if the page is `before` a cursor.
+    pub has_next_page: bool,
+    /// Whether there are more items before the page. Paginating with `first`, this is only
+    /// known to be true if the page is `after` a cursor.
+    pub has_previous_page: bool,
+}
+
+/// An opaque token pointing to a row of a [Cursor], to resume the pagination
+/// `after` or `before` it. It is serialized as a URL safe string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CursorToken(String);
+
+impl CursorOrder {
+    /// Order the column in the given direction
+    pub fn new(order: Order) -> Self {
+        Self { order, nulls: None }
+    }
+
+    /// Place the NULL values first
+    pub fn nulls_first(mut self) -> Self {
+        self.nulls = Some(NullOrdering::First);
+        self
+    }
+
+    /// Place the NULL values last
+    pub fn nulls_last(mut self) -> Self {
+        self.nulls = Some(NullOrdering::Last);
+        self
+    }
+
+    /// The opposite order, to fetch the last rows
+    fn reverse(&self) -> Self {
+        Self {
+            order: match self.order {
+                Order::Desc => Order::Asc,
+                _ => Order::Desc,
+            },
+            nulls: self.nulls.map(|nulls| match nulls {
+                NullOrdering::First => NullOrdering::Last,
+                NullOrdering::Last => NullOrdering::First,
+            }),
+        }
+    }
+}
+
+impl From<Order> for CursorOrder {
+    fn from(order: Order) -> Self {
+        Self::new(order)
+    }
+}
+
 impl<S> Cursor<S>
 where
     S: SelectorTrait,
@@ -33,119 +104,175 @@ where
     where
         C: IntoIdentity,
     {
+        let order_columns = order_columns.into_identity();
         Self {
             query,
             table,
-            order_columns: order_columns.into_identity(),
+            orders: vec![CursorOrder::new(Order::Asc); order_columns.arity()],
+            order_columns,
+            num_rows: None,
+            before: None,
+            after: None,
             last: false,
             phantom: PhantomData,
         }
     }
 
-    /// Filter paginated result with corresponding column less than the input value
-    pub fn before<V>(&mut self, values: V) -> &mut Self
+    /// Set the order of each column, ascending by default
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of orders doesn't match the number of columns.
+    pub fn order<I>(&mut self, orders: I) -> &mut Self
     where
-        V: IntoValueTuple,
+        I: IntoIterator,
+        I::Item: Into<CursorOrder>,
     {
-        let condition = self.apply_filter(values, |c, v| {
-            Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c))).lt(v)
-        });
-        self.query.cond_where(condition);
+        let orders: Vec<CursorOrder> = orders.into_iter().map(Into::into).collect();
+        if self.order_columns.arity() != orders.len() {
+            panic!("column arity mismatch");
+        }
+        self.orders = orders;
         self
     }
 
-    /// Filter paginated result with corresponding column greater than the input value
-    pub fn after<V>(&mut self, values: V) -> &mut Self
+    /// Filter paginated result with the rows preceding the input value in the order of the cursor
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values doesn't match the number of columns.
+    pub fn before<V>(&mut self, values: V) -> &mut Self
     where
         V: IntoValueTuple,
     {
-        let condition = self.apply_filter(values, |c, v| {
-            Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c))).gt(v)
-        });
-        self.query.cond_where(condition);
+        let values: Vec<Value> = values.into_value_tuple().into_iter().collect();
+        if self.order_columns.arity() != values.len() {
+            panic!("column arity mismatch");
+        }
+        self.before = Some(values);
         self
     }
 
-    fn apply_filter<V, F>(&self, values: V, f: F) -> Condition
+    /// Filter paginated result with the rows following the input value in the order of the cursor
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values doesn't match the number of columns.
+    pub fn after<V>(&mut self, values: V) -> &mut Self
     where
         V: IntoValueTuple,
-        F: Fn(&DynIden, Value) -> SimpleExpr,
     {
-        match (&self.order_columns, values.into_value_tuple()) {
-            (Identity::Unary(c1), ValueTuple::One(v1)) => Condition::all().add(f(c1, v1)),
-            (Identity::Binary(c1, c2), ValueTuple::Two(v1, v2)) => Condition::any()
-                .add(
-                    Condition::all()
-                        .add(
-                            Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c1))).eq(v1.clone()),
-                        )
-                        .add(f(c2, v2)),
-                )
-                .add(f(c1, v1)),
-            (Identity::Ternary(c1, c2, c3), ValueTuple::Three(v1, v2, v3)) => Condition::any()
-                .add(
-                    Condition::all()
-                        .add(
-                            Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c1))).eq(v1.clone()),
-                        )
-                        .add(
-                            Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c2))).eq(v2.clone()),
-                        )
-                        .add(f(c3, v3)),
-                )
-                .add(
-                    Condition::all()
-                        .add(
-                            Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c1))).eq(v1.clone()),
-                        )
-                        .add(f(c2, v2)),
-                )
-                .add(f(c1, v1)),
-            _ => panic!("column arity mismatch"),
+        let values: Vec<Value> = values.into_value_tuple().into_iter().collect();
+        if self.order_columns.arity() != values.len() {
+            panic!("column arity mismatch");
+        }
+        self.after = Some(values);
+        self
+    }
+
+    /// Filter paginated result with the rows preceding the one the token points to
+    pub fn before_token(&mut self, token: &CursorToken) -> Result<&mut Self, DbErr> {
+        self.before = Some(self.decode_token(token)?);
+        Ok(self)
+    }
+
+    /// Filter paginated result with the rows following the one the token points to
+    pub fn after_token(&mut self, token: &CursorToken) -> Result<&mut Self, DbErr> {
+        self.after = Some(self.decode_token(token)?);
+        Ok(self)
+    }
+
+    /// For columns (c1, c2, ..., cn), the row is beyond the cursor if any of
+    /// (c1 = v1 AND ... AND c(n-1) = v(n-1) AND cn beyond vn), ..., (c1 = v1 AND c2 beyond v2), c1 beyond v1
+    fn apply_filter(&self, values: &[Value], forward: bool) -> Condition {
+        let columns: Vec<&DynIden> = self.order_columns.iter().collect();
+        if columns.len() == 1 {
+            return Condition::all().add(self.beyond(0, &values[0], forward));
+        }
+        let mut condition = Condition::any();
+        for n in (1..columns.len()).rev() {
+            let mut inner = Condition::all();
+            for (c, v) in columns.iter().zip(values.iter()).take(n) {
+                let col = Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c)));
+                inner = inner.add(if is_null(v) {
+                    col.is_null()
+                } else {
+                    col.eq(v.clone())
+                });
+            }
+            condition = condition.add(inner.add(self.beyond(n, &values[n], forward)));
+        }
+        condition.add(self.beyond(0, &values[0], forward))
+    }
+
+    /// The condition for the n-th column to be beyond the value, i.e. after it when moving forward
+    fn beyond(&self, n: usize, value: &Value, forward: bool) -> Condition {
+        let column = self.order_columns.iter().nth(n).expect("Column exists");
+        let col = Expr::col((SeaRc::clone(&self.table), SeaRc::clone(column)));
+        let CursorOrder { order, nulls } = &self.orders[n];
+        let greater = matches!(order, Order::Desc) != forward;
+        let compare = |v: Value| -> SimpleExpr {
+            if greater {
+                col.clone().gt(v)
+            } else {
+                col.clone().lt(v)
+            }
+        };
+        let nulls_beyond = match nulls {
+            Some(nulls) => matches!(nulls, NullOrdering::Last) == forward,
+            None => return Condition::all().add(compare(value.clone())),
+        };
+        match (is_null(value), nulls_beyond) {
+            // there's no order among NULL values
+            (true, true) => Condition::any(),
+            (true, false) => Condition::all().add(col.is_not_null()),
+            (false, true) => Condition::any()
+                .add(compare(value.clone()))
+                .add(col.is_null()),
+            (false, false) => Condition::all().add(compare(value.clone())),
         }
     }
 
-    /// Limit result set to only first N rows in ascending order of the order by column
+    /// Limit result set to only first N rows in the order of the cursor
     pub fn first(&mut self, num_rows: u64) -> &mut Self {
-        self.query.limit(num_rows).clear_order_by();
-        let table = SeaRc::clone(&self.table);
-        self.apply_order_by(|query, col| {
-            query.order_by((SeaRc::clone(&table), SeaRc::clone(col)), Order::Asc);
-        });
+        self.num_rows = Some(num_rows);
         self.last = false;
         self
     }
 
-    /// Limit result set to only last N rows in ascending order of the order by column
+    /// Limit result set to only last N rows in the order of the cursor
     pub fn last(&mut self, num_rows: u64) -> &mut Self {
-        self.query.limit(num_rows).clear_order_by();
-        let table = SeaRc::clone(&self.table);
-        self.apply_order_by(|query, col| {
-            query.order_by((SeaRc::clone(&table), SeaRc::clone(col)), Order::Desc);
-        });
+        self.num_rows = Some(num_rows);
         self.last = true;
         self
     }
 
-    fn apply_order_by<F>(&mut self, f: F)
-    where
-        F: Fn(&mut SelectStatement, &DynIden),
-    {
-        let query = &mut self.query;
-        match &self.order_columns {
-            Identity::Unary(c1) => {
-                f(query, c1);
-            }
-            Identity::Binary(c1, c2) => {
-                f(query, c1);
-                f(query, c2);
-            }
-            Identity::Ternary(c1, c2, c3) => {
-                f(query, c1);
-                f(query, c2);
-                f(query, c3);
+    /// The query filtered `after` and `before` the cursors, limited to the given number of rows
+    /// in the order of the cursor, or in the opposite order to fetch the `last` rows
+    fn build_query(&self, num_rows: Option<u64>) -> SelectStatement {
+        let mut query = self.query.clone();
+        if let Some(values) = &self.after {
+            query.cond_where(self.apply_filter(values, true));
+        }
+        if let Some(values) = &self.before {
+            query.cond_where(self.apply_filter(values, false));
+        }
+        if let Some(num_rows) = num_rows {
+            query.limit(num_rows).clear_order_by();
+            for (col, order) in self.order_columns.iter().zip(self.orders.iter()) {
+                let CursorOrder { order, nulls } = if self.last {
+                    order.reverse()
+                } else {
+                    order.clone()
+                };
+                let col = (SeaRc::clone(&self.table), SeaRc::clone(col));
+                match nulls {
+                    Some(nulls) => query.order_by_with_nulls(col, order, nulls),
+                    None => query.order_by(col, order),
+                };
             }
         }
+        query
     }
 
     /// Fetch the paginated result
@@ -153,18 +280,89 @@ where
     where
         C: ConnectionTrait,
     {
-        let stmt = db.get_database_backend().build(&self.query);
+        let mut buffer = self.fetch(db, &self.build_query(self.num_rows)).await?;
+        if self.last {
+            buffer.reverse()
+        }
+        Ok(buffer)
+    }
+
+    /// Fetch the paginated result along with whether there are more rows around it.
+    /// One more row than requested with `first` or `last` is fetched to tell if there is a next page.
+    pub async fn page<C>(&mut self, db: &C) -> Result<CursorPage<S::Item>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let query = self.build_query(self.num_rows.map(|num_rows| num_rows + 1));
+        let mut items = self.fetch(db, &query).await?;
+        let has_more = match self.num_rows {
+            Some(num_rows) if items.len() as u64 > num_rows => {
+                items.truncate(num_rows as usize);
+                true
+            }
+            _ => false,
+        };
+        if self.last {
+            items.reverse();
+            Ok(CursorPage {
+                items,
+                has_next_page: self.before.is_some(),
+                has_previous_page: has_more,
+            })
+        } else {
+            Ok(CursorPage {
+                items,
+                has_next_page: has_more,
+                has_previous_page: self.after.is_some(),
+            })
+        }
+    }
+
+    async fn fetch<C>(&self, db: &C, query: &SelectStatement) -> Result<Vec<S::Item>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let stmt = db.get_database_backend().build(query);
         let rows = db.query_all(stmt).await?;
         let mut buffer = Vec::with_capacity(rows.len());
         for row in rows.into_iter() {
             buffer.push(S::from_raw_query_result(row)?);
         }
-        if self.last {
-            buffer.reverse()
-        }
         Ok(buffer)
     }
 
+    /// The token pointing to the given model, e.g. the last one of a page,
+    /// to fetch the next page `after` it
+    pub fn token<M>(&self, model: &M) -> Result<CursorToken, DbErr>
+    where
+        M: ModelTrait,
+    {
+        let values = self
+            .order_columns
+            .iter()
+            .map(|col| {
+                let name = col.to_string();
+                let column = <<M::Entity as EntityTrait>::Column as Iterable>::iter()
+                    .find(|column| column.as_str() == name)
+                    .ok_or_else(|| {
+                        DbErr::Custom(format!("Cursor column {name} is not a column of the model"))
+                    })?;
+                encode_value(&model.get(column))
+            })
+            .collect::<Result<Vec<_>, DbErr>>()?;
+        Ok(CursorToken(values.join(".")))
+    }
+
+    fn decode_token(&self, token: &CursorToken) -> Result<Vec<Value>, DbErr> {
+        let values = token
+            .0
+            .split('.')
+            .map(decode_value)
+            .collect::<Option<Vec<_>>>()
+            .filter(|values| values.len() == self.order_columns.arity());
+        values.ok_or_else(|| DbErr::Custom(format!("Invalid cursor token: {token}")))
+    }
+
     /// Construct a [Cursor] that fetch any custom struct
     pub fn into_model<M>(self) -> Cursor<SelectModel<M>>
     where
@@ -174,6 +372,10 @@ where
             query: self.query,
             table: self.table,
             order_columns: self.order_columns,
+            orders: self.orders,
+            num_rows: self.num_rows,
+            before: self.before,
+            after: self.after,
             last: self.last,
             phantom: PhantomData,
         }
@@ -186,6 +388,10 @@ where
             query: self.query,
             table: self.table,
             order_columns: self.order_columns,
+            orders: self.orders,
+            num_rows: self.num_rows,
+            before: self.before,
+            after: self.after,
             last: self.last,
             phantom: PhantomData,
         }
@@ -229,6 +435,373 @@ where
     }
 }
 
+impl CursorToken {
+    /// Get the token as a string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CursorToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for CursorToken {
+    fn from(token: String) -> Self {
+        Self(token)
+    }
+}
+
+impl serde::Serialize for CursorToken {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CursorToken {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = CursorToken;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a cursor token")
+            }
+
+            fn visit_str<E>(self, token: &str) -> Result<CursorToken, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(CursorToken(token.to_owned()))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+fn is_null(value: &Value) -> bool {
+    #[allow(unreachable_patterns)]
+    match value {
+        Value::Bool(v) => v.is_none(),
+        Value::TinyInt(v) => v.is_none(),
+        Value::SmallInt(v) => v.is_none(),
+        Value::Int(v) => v.is_none(),
+        Value::BigInt(v) => v.is_none(),
+        Value::TinyUnsigned(v) => v.is_none(),
+        Value::SmallUnsigned(v) => v.is_none(),
+        Value::Unsigned(v) => v.is_none(),
+        Value::BigUnsigned(v) => v.is_none(),
+        Value::Float(v) => v.is_none(),
+        Value::Double(v) => v.is_none(),
+        Value::String(v) => v.is_none(),
+        Value::Char(v) => v.is_none(),
+        Value::Bytes(v) => v.is_none(),
+        #[cfg(feature = "with-json")]
+        Value::Json(v) => v.is_none(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(v) => v.is_none(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(v) => v.is_none(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(v) => v.is_none(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(v) => v.is_none(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(v) => v.is_none(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(v) => v.is_none(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(v) => v.is_none(),
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(v) => v.is_none(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(v) => v.is_none(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(v) => v.is_none(),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(v) => v.is_none(),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(v) => v.is_none(),
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(v) => v.is_none(),
+        #[cfg(feature = "postgres-array")]
+        Value::Array(_, v) => v.is_none(),
+        // the types sea-orm doesn't enable, e.g. by another crate in the dependency graph
+        _ => false,
+    }
+}
+
+/// Encode a value as its type, followed by its hex encoded representation unless it's NULL
+fn encode_value(value: &Value) -> Result<String, DbErr> {
+    fn text<T: ToString>(value: &Option<T>) -> Option<Vec<u8>> {
+        value.as_ref().map(|value| value.to_string().into_bytes())
+    }
+
+    #[allow(unreachable_patterns)]
+    let (tag, payload) = match value {
+        Value::Bool(v) => ("b", text(v)),
+        Value::TinyInt(v) => ("i8", text(v)),
+        Value::SmallInt(v) => ("i16", text(v)),
+        Value::Int(v) => ("i32", text(v)),
+        Value::BigInt(v) => ("i64", text(v)),
+        Value::TinyUnsigned(v) => ("u8", text(v)),
+        Value::SmallUnsigned(v) => ("u16", text(v)),
+        Value::Unsigned(v) => ("u32", text(v)),
+        Value::BigUnsigned(v) => ("u64", text(v)),
+        Value::Float(v) => ("f32", text(v)),
+        Value::Double(v) => ("f64", text(v)),
+        Value::String(v) => ("s", text(v)),
+        Value::Char(v) => ("c", text(v)),
+        Value::Bytes(v) => ("y", v.as_ref().map(|v| v.to_vec())),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(v) => (
+            "cd",
+            text(&v.as_ref().map(|v| chrono::Datelike::num_days_from_ce(&**v))),
+        ),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(v) => (
+            "ct",
+            text(&v.as_ref().map(|v| {
+                use chrono::Timelike;
+                format!("{}.{}", v.num_seconds_from_midnight(), v.nanosecond())
+            })),
+        ),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(v) => ("cdt", text(&v.as_deref().map(format_chrono_date_time))),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(v) => (
+            "cdtu",
+            text(&v.as_ref().map(|v| format_chrono_date_time(&v.naive_utc()))),
+        ),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(v) => (
+            "cdtl",
+            text(&v.as_ref().map(|v| format_chrono_date_time(&v.naive_utc()))),
+        ),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(v) => (
+            "cdtz",
+            text(&v.as_ref().map(|v| {
+                format!(
+                    "{}.{}",
+                    format_chrono_date_time(&v.naive_utc()),
+                    v.offset().local_minus_utc()
+                )
+            })),
+        ),
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(v) => ("td", text(&v.as_ref().map(|v| v.to_julian_day()))),
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(v) => ("tt", text(&v.as_deref().map(format_time))),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(v) => (
+            "tdt",
+            text(
+                &v.as_ref()
+                    .map(|v| format!("{}.{}", v.date().to_julian_day(), format_time(&v.time()))),
+            ),
+        ),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(v) => (
+            "tdtz",
+            text(&v.as_ref().map(|v| {
+                format!(
+                    "{}.{}",
+                    v.unix_timestamp_nanos(),
+                    v.offset().whole_seconds()
+                )
+            })),
+        ),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(v) => ("uuid", text(v)),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(v) => ("dec", text(v)),
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(v) => ("bdec", text(v)),
+        _ => {
+            return Err(DbErr::Custom(format!(
+                "Cannot make a cursor token of {value:?}"
+            )))
+        }
+    };
+    Ok(match payload {
+        Some(payload) => {
+            let hex: String = payload.iter().map(|byte| format!("{byte:02x}")).collect();
+            format!("{tag}~{hex}")
+        }
+        None => tag.to_owned(),
+    })
+}
+
+fn decode_value(part: &str) -> Option<Value> {
+    /// Parse the text unless it's NULL, the outer option being None if it's invalid
+    fn parse<T, F>(text: Option<&str>, f: F) -> Option<Option<Box<T>>>
+    where
+        F: FnOnce(&str) -> Option<T>,
+    {
+        match text {
+            Some(text) => f(text).map(|v| Some(Box::new(v))),
+            None => Some(None),
+        }
+    }
+
+    fn from_str<T: FromStr>(text: Option<&str>) -> Option<Option<T>> {
+        parse(text, |text| text.parse().ok()).map(|v| v.map(|v| *v))
+    }
+
+    let (tag, payload) = match part.split_once('~') {
+        Some((tag, hex)) if hex.len() % 2 == 0 => {
+            let bytes = (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+                .collect::<Option<Vec<u8>>>()?;
+            (tag, Some(bytes))
+        }
+        Some(_) => return None,
+        None => (part, None),
+    };
+    if tag == "y" {
+        return Some(Value::Bytes(payload.map(Box::new)));
+    }
+    let text = match &payload {
+        Some(payload) => Some(std::str::from_utf8(payload).ok()?),
+        None => None,
+    };
+
+    Some(match tag {
+        "b" => Value::Bool(from_str(text)?),
+        "i8" => Value::TinyInt(from_str(text)?),
+        "i16" => Value::SmallInt(from_str(text)?),
+        "i32" => Value::Int(from_str(text)?),
+        "i64" => Value::BigInt(from_str(text)?),
+        "u8" => Value::TinyUnsigned(from_str(text)?),
+        "u16" => Value::SmallUnsigned(from_str(text)?),
+        "u32" => Value::Unsigned(from_str(text)?),
+        "u64" => Value::BigUnsigned(from_str(text)?),
+        "f32" => Value::Float(from_str(text)?),
+        "f64" => Value::Double(from_str(text)?),
+        "s" => Value::String(parse(text, |text| Some(text.to_owned()))?),
+        "c" => Value::Char(from_str(text)?),
+        #[cfg(feature = "with-chrono")]
+        "cd" => Value::ChronoDate(parse(text, |text| {
+            chrono::NaiveDate::from_num_days_from_ce_opt(text.parse().ok()?)
+        })?),
+        #[cfg(feature = "with-chrono")]
+        "ct" => Value::ChronoTime(parse(text, |text| {
+            let (secs, nanos) = text.split_once('.')?;
+            chrono::NaiveTime::from_num_seconds_from_midnight_opt(
+                secs.parse().ok()?,
+                nanos.parse().ok()?,
+            )
+        })?),
+        #[cfg(feature = "with-chrono")]
+        "cdt" => Value::ChronoDateTime(parse(text, parse_chrono_date_time)?),
+        #[cfg(feature = "with-chrono")]
+        "cdtu" => Value::ChronoDateTimeUtc(parse(text, |text| {
+            Some(chrono::TimeZone::from_utc_datetime(
+                &chrono::Utc,
+                &parse_chrono_date_time(text)?,
+            ))
+        })?),
+        #[cfg(feature = "with-chrono")]
+        "cdtl" => Value::ChronoDateTimeLocal(parse(text, |text| {
+            Some(chrono::TimeZone::from_utc_datetime(
+                &chrono::Local,
+                &parse_chrono_date_time(text)?,
+            ))
+        })?),
+        #[cfg(feature = "with-chrono")]
+        "cdtz" => Value::ChronoDateTimeWithTimeZone(parse(text, |text| {
+            let (timestamp, offset) = text.rsplit_once('.')?;
+            Some(chrono::TimeZone::from_utc_datetime(
+                &chrono::FixedOffset::east_opt(offset.parse().ok()?)?,
+                &parse_chrono_date_time(timestamp)?,
+            ))
+        })?),
+        #[cfg(feature = "with-time")]
+        "td" => Value::TimeDate(parse(text, |text| {
+            time::Date::from_julian_day(text.parse().ok()?).ok()
+        })?),
+        #[cfg(feature = "with-time")]
+        "tt" => Value::TimeTime(parse(text, parse_time)?),
+        #[cfg(feature = "with-time")]
+        "tdt" => Value::TimeDateTime(parse(text, |text| {
+            let (date, time) = text.split_once('.')?;
+            Some(time::PrimitiveDateTime::new(
+                time::Date::from_julian_day(date.parse().ok()?).ok()?,
+                parse_time(time)?,
+            ))
+        })?),
+        #[cfg(feature = "with-time")]
+        "tdtz" => Value::TimeDateTimeWithTimeZone(parse(text, |text| {
+            let (timestamp, offset) = text.split_once('.')?;
+            Some(
+                time::OffsetDateTime::from_unix_timestamp_nanos(timestamp.parse().ok()?)
+                    .ok()?
+                    .to_offset(time::UtcOffset::from_whole_seconds(offset.parse().ok()?).ok()?),
+            )
+        })?),
+        #[cfg(feature = "with-uuid")]
+        "uuid" => Value::Uuid(parse(text, |text| uuid::Uuid::parse_str(text).ok())?),
+        #[cfg(feature = "with-rust_decimal")]
+        "dec" => Value::Decimal(parse(text, |text| text.parse().ok())?),
+        #[cfg(feature = "with-bigdecimal")]
+        "bdec" => Value::BigDecimal(parse(text, |text| text.parse().ok())?),
+        _ => return None,
+    })
+}
+
+#[cfg(feature = "with-chrono")]
+fn format_chrono_date_time(date_time: &chrono::NaiveDateTime) -> String {
+    use chrono::{Datelike, Timelike};
+    format!(
+        "{}.{}.{}",
+        date_time.num_days_from_ce(),
+        date_time.num_seconds_from_midnight(),
+        date_time.nanosecond()
+    )
+}
+
+#[cfg(feature = "with-chrono")]
+fn parse_chrono_date_time(text: &str) -> Option<chrono::NaiveDateTime> {
+    let mut parts = text.split('.');
+    let date = chrono::NaiveDate::from_num_days_from_ce_opt(parts.next()?.parse().ok()?)?;
+    let time = chrono::NaiveTime::from_num_seconds_from_midnight_opt(
+        parts.next()?.parse().ok()?,
+        parts.next()?.parse().ok()?,
+    )?;
+    Some(date.and_time(time))
+}
+
+#[cfg(feature = "with-time")]
+fn format_time(time: &time::Time) -> String {
+    let (hour, minute, second, nano) = time.as_hms_nano();
+    format!("{hour}.{minute}.{second}.{nano}")
+}
+
+#[cfg(feature = "with-time")]
+fn parse_time(text: &str) -> Option<time::Time> {
+    let mut parts = text.split('.').map(|part| part.parse::<u32>().ok());
+    let mut next = || parts.next().flatten();
+    time::Time::from_hms_nano(
+        u8::try_from(next()?).ok()?,
+        u8::try_from(next()?).ok()?,
+        u8::try_from(next()?).ok()?,
+        next()?,
+    )
+    .ok()
+}
+
 #[cfg(test)]
 #[cfg(feature = "mock")]
 mod tests {
@@ -451,6 +1024,29 @@ mod tests {
         impl ActiveModelBehavior for ActiveModel {}
     }
 
+    mod wxyz_entity {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "m")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub w: i32,
+            #[sea_orm(primary_key)]
+            pub x: i32,
+            #[sea_orm(primary_key)]
+            pub y: String,
+            #[sea_orm(primary_key)]
+            pub z: i64,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
     #[smol_potat::test]
     async fn composite_keys_1() -> Result<(), DbErr> {
         use test_entity::*;
@@ -665,4 +1261,197 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn composite_keys_6() -> Result<(), DbErr> {
+        use wxyz_entity::*;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[Model {
+                w: 'w' as i32,
+                x: 'x' as i32,
+                y: "y".into(),
+                z: 'z' as i64,
+            }]])
+            .into_connection();
+
+        assert!(!Entity::find()
+            .cursor_by((Column::W, Column::X, Column::Y, Column::Z))
+            .before(('w' as i32, 'x' as i32, "y".to_owned(), 'z' as i64))
+            .last(4)
+            .all(&db)
+            .await?
+            .is_empty());
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "m"."w", "m"."x", "m"."y", "m"."z""#,
+                    r#"FROM "m""#,
+                    r#"WHERE ("m"."w" = $1 AND "m"."x" = $2 AND "m"."y" = $3 AND "m"."z" < $4)"#,
+                    r#"OR ("m"."w" = $5 AND "m"."x" = $6 AND "m"."y" < $7)"#,
+                    r#"OR ("m"."w" = $8 AND "m"."x" < $9)"#,
+                    r#"OR "m"."w" < $10"#,
+                    r#"ORDER BY "m"."w" DESC, "m"."x" DESC, "m"."y" DESC, "m"."z" DESC"#,
+                    r#"LIMIT $11"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [
+                    ('w' as i32).into(),
+                    ('x' as i32).into(),
+                    "y".into(),
+                    ('z' as i64).into(),
+                    ('w' as i32).into(),
+                    ('x' as i32).into(),
+                    "y".into(),
+                    ('w' as i32).into(),
+                    ('x' as i32).into(),
+                    ('w' as i32).into(),
+                    4_u64.into(),
+                ]
+            ),])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn mixed_order_with_nulls() -> Result<(), DbErr> {
+        use fruit::*;
+
+        let fruit = |id, cake_id| Model {
+            id,
+            name: "Apple".into(),
+            cake_id,
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                vec![fruit(6, Some(1)), fruit(2, None), fruit(3, None)],
+                vec![fruit(6, Some(1))],
+            ])
+            .into_connection();
+
+        let cursor = || {
+            let mut cursor = Entity::find().cursor_by((Column::CakeId, Column::Id));
+            cursor.order([
+                CursorOrder::new(Order::Desc).nulls_last(),
+                Order::Asc.into(),
+            ]);
+            cursor
+        };
+        let page = cursor().after((1, 5)).first(2).page(&db).await?;
+        assert_eq!(
+            page,
+            CursorPage {
+                items: vec![fruit(6, Some(1)), fruit(2, None)],
+                has_next_page: true,
+                has_previous_page: true,
+            }
+        );
+
+        let token = cursor().token(&page.items[1])?;
+        assert_eq!(token.as_str(), "i32.i32~32");
+        // the order applies to the cursor and the limit set before it
+        let page = Entity::find()
+            .cursor_by((Column::CakeId, Column::Id))
+            .before_token(&token)?
+            .last(2)
+            .order([
+                CursorOrder::new(Order::Desc).nulls_last(),
+                Order::Asc.into(),
+            ])
+            .page(&db)
+            .await?;
+        assert_eq!(
+            page,
+            CursorPage {
+                items: vec![fruit(6, Some(1))],
+                has_next_page: true,
+                has_previous_page: false,
+            }
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+                        r#"FROM "fruit""#,
+                        r#"WHERE ("fruit"."cake_id" = $1 AND "fruit"."id" > $2)"#,
+                        r#"OR ("fruit"."cake_id" < $3 OR "fruit"."cake_id" IS NULL)"#,
+                        r#"ORDER BY "fruit"."cake_id" DESC NULLS LAST, "fruit"."id" ASC"#,
+                        r#"LIMIT $4"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [1_i32.into(), 5_i32.into(), 1_i32.into(), 3_u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+                        r#"FROM "fruit""#,
+                        r#"WHERE ("fruit"."cake_id" IS NULL AND "fruit"."id" < $1)"#,
+                        r#"OR "fruit"."cake_id" IS NOT NULL"#,
+                        r#"ORDER BY "fruit"."cake_id" ASC NULLS FIRST, "fruit"."id" DESC"#,
+                        r#"LIMIT $2"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [2_i32.into(), 3_u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn invalid_cursor_token() {
+        use fruit::*;
+
+        let mut cursor = Entity::find().cursor_by((Column::CakeId, Column::Id));
+        for token in ["i32~31", "i32.i32~3", "x.i32~31"] {
+            assert_eq!(
+                cursor
+                    .after_token(&CursorToken::from(token.to_owned()))
+                    .map(|_| ()),
+                Err(DbErr::Custom(format!("Invalid cursor token: {token}")))
+            );
+        }
+    }
+
+    #[test]
+    fn cursor_token_values() {
+        let values = vec![
+            Value::Bool(Some(true)),
+            Value::BigInt(Some(-42)),
+            Value::Double(Some(0.1)),
+            Value::String(Some(Box::new("Cheese.Cake~".to_owned()))),
+            Value::Bytes(Some(Box::new(vec![0, 255]))),
+            Value::Int(None),
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(
+                chrono::DateTime::parse_from_rfc3339("2023-01-02T03:04:05.678+09:30")
+                    .expect("Valid date time"),
+            ))),
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTimeWithTimeZone(Some(Box::new(
+                time::OffsetDateTime::from_unix_timestamp_nanos(1_672_628_645_678_000_000)
+                    .expect("Valid date time")
+                    .to_offset(time::UtcOffset::from_hms(-3, 0, 0).expect("Valid offset")),
+            ))),
+            #[cfg(feature = "with-uuid")]
+            Value::Uuid(Some(Box::new(uuid::Uuid::from_u128(42)))),
+        ];
+        for value in values {
+            let encoded = encode_value(&value).expect("Value can be encoded");
+            assert_eq!(decode_value(&encoded), Some(value));
+        }
+    }
 }
@@ -1,7 +1,8 @@
 use crate::{
-    error::*, ActiveModelTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait, Statement,
+    error::*, query::version_of, ActiveModelTrait, ColumnTrait, ConnectionTrait, DeleteMany,
+    DeleteOne, EntityTrait, Iterable, SelectModel, SelectorRaw, Statement,
 };
-use sea_query::DeleteStatement;
+use sea_query::{DeleteStatement, Expr, Query, UpdateStatement};
 use std::future::Future;
 
 /// Handles DELETE operations in a ActiveModel using [DeleteStatement]
@@ -26,8 +27,39 @@ where
     where
         C: ConnectionTrait,
     {
+        let check_version = version_of(&self.model).is_some();
+        let builder = db.get_database_backend();
+        let statement = self
+            .soft_delete_query()
+            .map(|soft_delete| match soft_delete {
+                Some(query) => builder.build(&query),
+                None => builder.build(&self.query),
+            });
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        async move { exec_delete(statement?, check_version, db).await }
+    }
+
+    /// Execute a DELETE operation on one ActiveModel and return the deleted model
+    /// (use `RETURNING` syntax, only if database supported)
+    pub fn exec_with_returning<C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Option<<A::Entity as EntityTrait>::Model>, DbErr>> + '_
+    where
+        C: ConnectionTrait,
+    {
+        let check_version = version_of(&self.model).is_some();
+        let statement = self
+            .soft_delete_query()
+            .map(|soft_delete| build_with_returning::<A::Entity>(soft_delete, self.query, db));
+        // so that self is dropped before entering await
+        async move {
+            let mut models = exec_delete_with_returning::<A::Entity, _>(statement?, db).await?;
+            match models.pop() {
+                None if check_version => Err(DbErr::RecordVersionMismatch),
+                model => Ok(model),
+            }
+        }
     }
 }
 
@@ -40,8 +72,31 @@ where
     where
         C: ConnectionTrait,
     {
+        let builder = db.get_database_backend();
+        let statement = self
+            .soft_delete_query()
+            .map(|soft_delete| match soft_delete {
+                Some(query) => builder.build(&query),
+                None => builder.build(&self.query),
+            });
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        async move { exec_delete(statement?, false, db).await }
+    }
+
+    /// Execute a DELETE operation on many ActiveModels and return the deleted models
+    /// (use `RETURNING` syntax, only if database supported)
+    pub fn exec_with_returning<C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<E::Model>, DbErr>> + '_
+    where
+        C: ConnectionTrait,
+    {
+        let statement = self
+            .soft_delete_query()
+            .map(|soft_delete| build_with_returning::<E>(soft_delete, self.query, db));
+        // so that self is dropped before entering await
+        async move { exec_delete_with_returning::<E, _>(statement?, db).await }
     }
 }
 
@@ -57,23 +112,125 @@ impl Deleter {
         C: ConnectionTrait,
     {
         let builder = db.get_database_backend();
-        exec_delete(builder.build(&self.query), db)
+        exec_delete(builder.build(&self.query), false, db)
     }
 }
 
-async fn exec_delete_only<C>(query: DeleteStatement, db: &C) -> Result<DeleteResult, DbErr>
+async fn exec_delete<C>(
+    statement: Statement,
+    check_version: bool,
+    db: &C,
+) -> Result<DeleteResult, DbErr>
 where
     C: ConnectionTrait,
 {
-    Deleter::new(query).exec(db).await
+    let result = db.execute(statement).await?;
+    if check_version && result.rows_affected() == 0 {
+        return Err(DbErr::RecordVersionMismatch);
+    }
+    Ok(DeleteResult {
+        rows_affected: result.rows_affected(),
+    })
+}
+
+fn build_with_returning<E>(
+    soft_delete: Option<UpdateStatement>,
+    mut query: DeleteStatement,
+    db: &impl ConnectionTrait,
+) -> Statement
+where
+    E: EntityTrait,
+{
+    let builder = db.get_database_backend();
+    let returning = Query::returning().exprs(E::Column::iter().map(|c| c.select_as(Expr::col(c))));
+    match soft_delete {
+        Some(mut query) => builder.build(query.returning(returning)),
+        None => builder.build(query.returning(returning)),
+    }
 }
 
-async fn exec_delete<C>(statement: Statement, db: &C) -> Result<DeleteResult, DbErr>
+async fn exec_delete_with_returning<E, C>(
+    statement: Statement,
+    db: &C,
+) -> Result<Vec<E::Model>, DbErr>
 where
+    E: EntityTrait,
     C: ConnectionTrait,
 {
-    let result = db.execute(statement).await?;
-    Ok(DeleteResult {
-        rows_affected: result.rows_affected(),
-    })
+    if !db.support_returning() {
+        return Err(DbErr::BackendNotSupported {
+            db: db.get_database_backend().as_str(),
+            ctx: "DELETE RETURNING",
+        });
+    }
+    SelectorRaw::<SelectModel<E::Model>>::from_statement(statement)
+        .all(db)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn delete_with_returning() -> Result<(), DbErr> {
+        let apple = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: None,
+        };
+        let pineapple = fruit::Model {
+            id: 2,
+            name: "Pineapple".to_owned(),
+            cake_id: None,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([vec![apple.clone(), pineapple.clone()], vec![apple.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::delete_many()
+                .filter(fruit::Column::Name.contains("Apple"))
+                .exec_with_returning(&db)
+                .await?,
+            [apple.clone(), pineapple]
+        );
+
+        assert_eq!(
+            fruit::Entity::delete(apple.clone().into_active_model())
+                .exec_with_returning(&db)
+                .await?,
+            Some(apple.clone())
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "fruit" WHERE "fruit"."name" LIKE $1 RETURNING "id", "name", "cake_id""#,
+                    ["%Apple%".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "fruit" WHERE "fruit"."id" = $1 RETURNING "id", "name", "cake_id""#,
+                    [1i32.into()]
+                ),
+            ]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert_eq!(
+            fruit::Entity::delete_many().exec_with_returning(&db).await,
+            Err(DbErr::BackendNotSupported {
+                db: "MySQL",
+                ctx: "DELETE RETURNING",
+            })
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,548 @@
+use crate::{error::*, ConnectionTrait, DbBackend, QueryResult, Statement};
+
+/// The query plan of a statement, normalized across database backends.
+///
+/// It's parsed from the output of `EXPLAIN` on PostgreSQL, `EXPLAIN FORMAT=TREE` on MySQL
+/// (8.0.18 or later) and `EXPLAIN QUERY PLAN` on SQLite.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryPlan {
+    /// The top level steps of the plan
+    pub nodes: Vec<PlanNode>,
+    /// The output of `EXPLAIN` as returned by the database, one line per row
+    pub raw: String,
+}
+
+/// A step of a [QueryPlan]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanNode {
+    /// The kind of step, the same across database backends
+    pub kind: PlanNodeKind,
+    /// The step as described by the database, without its estimates,
+    /// e.g. `Seq Scan on cake` on PostgreSQL or `SEARCH fruit USING INDEX idx_cake_id` on SQLite
+    pub description: String,
+    /// The table the step reads from
+    pub table: Option<String>,
+    /// The index the step uses
+    pub index: Option<String>,
+    /// The estimated number of rows the step returns (not on SQLite)
+    pub estimated_rows: Option<f64>,
+    /// The estimated cost of the step, in the unit of the database (not on SQLite)
+    pub cost: Option<f64>,
+    /// The number of rows the step actually returned, with `explain_analyze`
+    pub actual_rows: Option<f64>,
+    /// The steps this step takes its input from
+    pub children: Vec<PlanNode>,
+}
+
+/// The kind of a [PlanNode], normalized across database backends
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PlanNodeKind {
+    /// Read every row of a table, e.g. `Seq Scan` on PostgreSQL, `Table scan` on MySQL
+    /// or `SCAN` without an index on SQLite
+    FullScan,
+    /// Find the rows through an index, then read them from the table
+    IndexScan,
+    /// Read the rows from an index alone, e.g. `Index Only Scan` on PostgreSQL
+    /// or a covering index on MySQL and SQLite
+    IndexOnlyScan,
+    /// Join the rows of two steps
+    Join,
+    /// Sort the rows
+    Sort,
+    /// Any other step, e.g. a filter or an aggregate
+    Other,
+}
+
+impl QueryPlan {
+    /// All the steps of the plan, depth first
+    pub fn iter(&self) -> impl Iterator<Item = &PlanNode> {
+        let mut stack: Vec<&PlanNode> = self.nodes.iter().rev().collect();
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// The indexes used by the plan
+    pub fn indexes(&self) -> Vec<&str> {
+        self.iter()
+            .filter_map(|node| node.index.as_deref())
+            .collect()
+    }
+
+    /// Whether any step of the plan uses an index
+    pub fn uses_index(&self) -> bool {
+        self.iter().any(|node| node.index.is_some())
+    }
+}
+
+/// Explain the statement, or run and explain it if `analyze` is set
+pub(crate) async fn explain<C>(db: &C, stmt: Statement, analyze: bool) -> Result<QueryPlan, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let backend = db.get_database_backend();
+    let prefix = match (backend, analyze) {
+        (DbBackend::MySql, false) => "EXPLAIN FORMAT=TREE",
+        (DbBackend::Postgres, false) => "EXPLAIN",
+        (DbBackend::MySql | DbBackend::Postgres, true) => "EXPLAIN ANALYZE",
+        (DbBackend::Sqlite, false) => "EXPLAIN QUERY PLAN",
+        (DbBackend::Sqlite, true) => {
+            return Err(DbErr::BackendNotSupported {
+                db: backend.as_str(),
+                ctx: "EXPLAIN ANALYZE",
+            })
+        }
+    };
+    let rows = db
+        .query_all(Statement {
+            sql: format!("{prefix} {}", stmt.sql),
+            values: stmt.values,
+            db_backend: backend,
+            table: stmt.table,
+        })
+        .await?;
+    match backend {
+        DbBackend::MySql => text_plan(&rows, "EXPLAIN"),
+        DbBackend::Postgres => text_plan(&rows, "QUERY PLAN"),
+        DbBackend::Sqlite => sqlite_plan(&rows),
+    }
+}
+
+/// Parse a plan printed as a tree, each step starting with `->` but the root on PostgreSQL,
+/// followed by its estimates, e.g. `-> Table scan on cake  (cost=0.35 rows=1)`
+fn text_plan(rows: &[QueryResult], col: &str) -> Result<QueryPlan, DbErr> {
+    let raw = rows
+        .iter()
+        .map(|row| row.try_get::<String>("", col))
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n");
+
+    let mut nodes = Vec::new();
+    let mut stack: Vec<(usize, PlanNode)> = Vec::new();
+    for line in raw.lines() {
+        let content = line.trim_start();
+        let indent = line.len() - content.len();
+        let text = match content.strip_prefix("->") {
+            Some(text) => text.trim_start(),
+            // the root of a PostgreSQL plan
+            None if nodes.is_empty() && stack.is_empty() && !content.is_empty() => content,
+            // the details of a step, e.g. `Filter: (id = 1)` or `Planning Time: 0.1 ms`
+            None => continue,
+        };
+        while stack.last().map_or(false, |(depth, _)| *depth >= indent) {
+            pop_node(&mut stack, &mut nodes);
+        }
+        stack.push((indent, text_node(text)));
+    }
+    while !stack.is_empty() {
+        pop_node(&mut stack, &mut nodes);
+    }
+
+    Ok(QueryPlan { nodes, raw })
+}
+
+fn pop_node(stack: &mut Vec<(usize, PlanNode)>, nodes: &mut Vec<PlanNode>) {
+    if let Some((_, node)) = stack.pop() {
+        match stack.last_mut() {
+            Some((_, parent)) => parent.children.push(node),
+            None => nodes.push(node),
+        }
+    }
+}
+
+fn text_node(text: &str) -> PlanNode {
+    let estimates_start = ["(cost=", "(actual ", "(never executed)"]
+        .iter()
+        .filter_map(|estimates| text.find(estimates))
+        .min()
+        .unwrap_or(text.len());
+    let (description, estimates) = text.split_at(estimates_start);
+    let description = description.trim_end();
+
+    // e.g. `Filter: (cake.id = 1)` or `Inner hash join (fruit.cake_id = cake.id)`
+    let head = [": ", " ("]
+        .iter()
+        .filter_map(|separator| description.find(separator))
+        .min()
+        .map_or(description, |end| &description[..end]);
+    let node_type = [" using ", " on "]
+        .iter()
+        .filter_map(|separator| head.find(separator))
+        .min()
+        .map_or(head, |end| &head[..end]);
+    let word_after = |keyword: &str| {
+        let start = head.find(keyword)? + keyword.len();
+        head[start..]
+            .split_whitespace()
+            .next()
+            .map(|word| word.chars().filter(|c| !matches!(c, '"' | '`')).collect())
+    };
+    let (table, index) = match word_after(" using ") {
+        Some(index) => (word_after(" on "), Some(index)),
+        // `Bitmap Index Scan on cake_name_idx` of PostgreSQL scans an index only
+        None if node_type.ends_with("Index Scan") => (None, word_after(" on ")),
+        None => (word_after(" on "), None),
+    };
+
+    let estimated = estimates_group(estimates, "(cost=");
+    let actual = estimates_group(estimates, "(actual ");
+    PlanNode {
+        kind: text_node_kind(node_type),
+        description: description.to_owned(),
+        table,
+        index,
+        estimated_rows: estimated.and_then(|group| estimate(group, "rows")),
+        cost: estimated.and_then(|group| estimate(group, "cost")),
+        actual_rows: actual.and_then(|group| estimate(group, "rows")),
+        children: Vec::new(),
+    }
+}
+
+/// The kind of a step of PostgreSQL, e.g. `Index Only Scan` or `Hash Join`,
+/// or of MySQL, e.g. `Covering index lookup` or `Nested loop inner join`
+fn text_node_kind(node_type: &str) -> PlanNodeKind {
+    let node_type = node_type.to_lowercase();
+    if node_type.contains("join") || node_type == "nested loop" {
+        PlanNodeKind::Join
+    } else if node_type.contains("sort") {
+        PlanNodeKind::Sort
+    } else if node_type.contains("index only scan") || node_type.starts_with("covering index") {
+        PlanNodeKind::IndexOnlyScan
+    } else if node_type.contains("index") || node_type.starts_with("bitmap") {
+        PlanNodeKind::IndexScan
+    } else if node_type.contains("seq scan") || node_type.contains("table scan") {
+        PlanNodeKind::FullScan
+    } else {
+        PlanNodeKind::Other
+    }
+}
+
+/// The content of the parentheses opened with the given prefix
+fn estimates_group<'a>(estimates: &'a str, open: &str) -> Option<&'a str> {
+    let start = estimates.find(open)? + 1;
+    let end = start + estimates[start..].find(')')?;
+    Some(&estimates[start..end])
+}
+
+/// The value of the estimate, the upper bound of a range such as `cost=0.15..8.17`
+fn estimate(group: &str, key: &str) -> Option<f64> {
+    group
+        .split_whitespace()
+        .find_map(|pair| pair.strip_prefix(key)?.strip_prefix('='))?
+        .rsplit("..")
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Parse the rows of `EXPLAIN QUERY PLAN`, each step referring to its parent by id
+fn sqlite_plan(rows: &[QueryResult]) -> Result<QueryPlan, DbErr> {
+    let steps = rows
+        .iter()
+        .map(|row| {
+            Ok((
+                row.try_get::<i32>("", "id")?,
+                row.try_get::<i32>("", "parent")?,
+                row.try_get::<String>("", "detail")?,
+            ))
+        })
+        .collect::<Result<Vec<_>, DbErr>>()?;
+    let raw = steps
+        .iter()
+        .map(|(_, _, detail)| detail.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    fn children(steps: &[(i32, i32, String)], parent: i32) -> Vec<PlanNode> {
+        steps
+            .iter()
+            .filter(|(_, step_parent, _)| *step_parent == parent)
+            .map(|(id, _, detail)| PlanNode {
+                children: children(steps, *id),
+                ..sqlite_node(detail)
+            })
+            .collect()
+    }
+
+    Ok(QueryPlan {
+        nodes: children(&steps, 0),
+        raw,
+    })
+}
+
+fn sqlite_node(detail: &str) -> PlanNode {
+    let mut words = detail.split_whitespace();
+    let node_type = match words.next() {
+        Some(node_type @ ("SCAN" | "SEARCH")) => node_type,
+        _ => detail,
+    };
+    let table = if node_type == detail {
+        None
+    } else {
+        words
+            .find(|word| *word != "TABLE")
+            .map(|table| table.trim_matches('"').to_owned())
+    };
+    let index = detail.find(" USING ").and_then(|start| {
+        let using = detail[start + " USING ".len()..].trim_start_matches("COVERING ");
+        if let Some(index) = using.strip_prefix("INDEX ") {
+            index.split_whitespace().next().map(ToOwned::to_owned)
+        } else {
+            ["INTEGER PRIMARY KEY", "PRIMARY KEY"]
+                .iter()
+                .find(|key| using.starts_with(*key))
+                .map(|key| (*key).to_owned())
+        }
+    });
+    let kind = match node_type {
+        "SCAN" | "SEARCH" if detail.contains(" USING COVERING INDEX ") => {
+            PlanNodeKind::IndexOnlyScan
+        }
+        "SCAN" | "SEARCH" if index.is_some() => PlanNodeKind::IndexScan,
+        "SCAN" => PlanNodeKind::FullScan,
+        _ if detail.ends_with(" FOR ORDER BY") => PlanNodeKind::Sort,
+        _ => PlanNodeKind::Other,
+    };
+    PlanNode {
+        kind,
+        description: detail.to_owned(),
+        table,
+        index,
+        estimated_rows: None,
+        cost: None,
+        actual_rows: None,
+        children: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::{PlanNode, PlanNodeKind};
+    use crate::{
+        entity::*, error::*, tests_cfg::*, DbBackend, MockDatabase, QueryFilter, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn node(kind: PlanNodeKind, description: &str) -> PlanNode {
+        PlanNode {
+            kind,
+            description: description.to_owned(),
+            table: None,
+            index: None,
+            estimated_rows: None,
+            cost: None,
+            actual_rows: None,
+            children: Vec::new(),
+        }
+    }
+
+    #[smol_potat::test]
+    async fn explain_select() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                maplit::btreemap! { "QUERY PLAN" => "Nested Loop  (cost=0.30..16.35 rows=1 width=72) (actual time=0.01..0.02 rows=1 loops=1)".into() },
+                maplit::btreemap! { "QUERY PLAN" => "  ->  Index Scan using cake_pkey on cake  (cost=0.15..8.17 rows=1 width=36) (actual time=0.01..0.01 rows=1 loops=1)".into() },
+                maplit::btreemap! { "QUERY PLAN" => "        Index Cond: (id = 1)".into() },
+                maplit::btreemap! { "QUERY PLAN" => "  ->  Seq Scan on fruit  (cost=0.00..8.17 rows=1 width=36) (actual time=0.00..0.00 rows=1 loops=1)".into() },
+                maplit::btreemap! { "QUERY PLAN" => "        Filter: (cake_id = 1)".into() },
+                maplit::btreemap! { "QUERY PLAN" => "Planning Time: 0.102 ms".into() },
+            ]])
+            .into_connection();
+
+        let plan = cake::Entity::find_by_id(1)
+            .find_also_related(fruit::Entity)
+            .explain_analyze(&db)
+            .await?;
+        assert_eq!(
+            plan.nodes,
+            [PlanNode {
+                estimated_rows: Some(1.0),
+                cost: Some(16.35),
+                actual_rows: Some(1.0),
+                children: vec![
+                    PlanNode {
+                        table: Some("cake".to_owned()),
+                        index: Some("cake_pkey".to_owned()),
+                        estimated_rows: Some(1.0),
+                        cost: Some(8.17),
+                        actual_rows: Some(1.0),
+                        ..node(
+                            PlanNodeKind::IndexScan,
+                            "Index Scan using cake_pkey on cake"
+                        )
+                    },
+                    PlanNode {
+                        table: Some("fruit".to_owned()),
+                        estimated_rows: Some(1.0),
+                        cost: Some(8.17),
+                        actual_rows: Some(1.0),
+                        ..node(PlanNodeKind::FullScan, "Seq Scan on fruit")
+                    },
+                ],
+                ..node(PlanNodeKind::Join, "Nested Loop")
+            }]
+        );
+        assert!(plan.uses_index());
+        assert_eq!(plan.indexes(), ["cake_pkey"]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"EXPLAIN ANALYZE SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                    r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name", "fruit"."cake_id" AS "B_cake_id""#,
+                    r#"FROM "cake""#,
+                    r#"LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                    r#"WHERE "cake"."id" = $1"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into()]
+            )]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([[maplit::btreemap! {
+                "EXPLAIN" => [
+                    "-> Filter: (cake.`name` like 'Cheese%')  (cost=0.45 rows=0.5)",
+                    "    -> Index range scan on cake using idx_name over ('Cheese' <= name <= 'Cheesf')  (cost=0.45 rows=1)",
+                ]
+                .join("\n")
+                .into()
+            }]])
+            .into_connection();
+
+        let plan = cake::Entity::find()
+            .filter(cake::Column::Name.starts_with("Cheese"))
+            .explain(&db)
+            .await?;
+        assert_eq!(
+            plan.nodes,
+            [PlanNode {
+                estimated_rows: Some(0.5),
+                cost: Some(0.45),
+                children: vec![PlanNode {
+                    table: Some("cake".to_owned()),
+                    index: Some("idx_name".to_owned()),
+                    estimated_rows: Some(1.0),
+                    cost: Some(0.45),
+                    ..node(
+                        PlanNodeKind::IndexScan,
+                        "Index range scan on cake using idx_name over ('Cheese' <= name <= 'Cheesf')"
+                    )
+                }],
+                ..node(PlanNodeKind::Other, "Filter: (cake.`name` like 'Cheese%')")
+            }]
+        );
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([[
+                maplit::btreemap! { "id" => 2i32.into(), "parent" => 0i32.into(), "detail" => "SCAN cake".into() },
+                maplit::btreemap! { "id" => 4i32.into(), "parent" => 0i32.into(), "detail" => "SEARCH fruit USING INDEX idx_cake_id (cake_id=?)".into() },
+                maplit::btreemap! { "id" => 9i32.into(), "parent" => 0i32.into(), "detail" => "USE TEMP B-TREE FOR ORDER BY".into() },
+            ]])
+            .into_connection();
+
+        let select = cake::Entity::find()
+            .find_also_related(fruit::Entity)
+            .into_model::<cake::Model, fruit::Model>();
+        assert_eq!(
+            select.clone().explain_analyze(&db).await,
+            Err(DbErr::BackendNotSupported {
+                db: "SQLite",
+                ctx: "EXPLAIN ANALYZE",
+            })
+        );
+        let plan = select.explain(&db).await?;
+        assert_eq!(
+            plan.nodes,
+            [
+                PlanNode {
+                    table: Some("cake".to_owned()),
+                    ..node(PlanNodeKind::FullScan, "SCAN cake")
+                },
+                PlanNode {
+                    table: Some("fruit".to_owned()),
+                    index: Some("idx_cake_id".to_owned()),
+                    ..node(
+                        PlanNodeKind::IndexScan,
+                        "SEARCH fruit USING INDEX idx_cake_id (cake_id=?)"
+                    )
+                },
+                node(PlanNodeKind::Sort, "USE TEMP B-TREE FOR ORDER BY"),
+            ]
+        );
+        assert_eq!(
+            plan.raw,
+            "SCAN cake\nSEARCH fruit USING INDEX idx_cake_id (cake_id=?)\nUSE TEMP B-TREE FOR ORDER BY"
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                [
+                    r#"EXPLAIN QUERY PLAN SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                    r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name", "fruit"."cake_id" AS "B_cake_id""#,
+                    r#"FROM "cake""#,
+                    r#"LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                ]
+                .join(" ")
+                .as_str(),
+                []
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn plan_node_kind() {
+        use super::{sqlite_node, text_node};
+
+        for (text, kind) in [
+            ("Parallel Seq Scan on cake", PlanNodeKind::FullScan),
+            ("Bitmap Heap Scan on cake", PlanNodeKind::IndexScan),
+            (
+                "Index Only Scan using cake_pkey on cake",
+                PlanNodeKind::IndexOnlyScan,
+            ),
+            ("Hash Join", PlanNodeKind::Join),
+            ("Incremental Sort", PlanNodeKind::Sort),
+            ("Table scan on cake", PlanNodeKind::FullScan),
+            (
+                "Single-row index lookup on cake using PRIMARY (id=1)",
+                PlanNodeKind::IndexScan,
+            ),
+            (
+                "Covering index lookup on fruit using idx_cake_id (cake_id=1)",
+                PlanNodeKind::IndexOnlyScan,
+            ),
+            ("Nested loop inner join", PlanNodeKind::Join),
+            ("Sort: cake.`name`", PlanNodeKind::Sort),
+            ("Aggregate", PlanNodeKind::Other),
+        ] {
+            assert_eq!(text_node(text).kind, kind, "{text}");
+        }
+
+        for (detail, kind) in [
+            ("SCAN cake", PlanNodeKind::FullScan),
+            ("SCAN cake USING INDEX idx_name", PlanNodeKind::IndexScan),
+            (
+                "SEARCH cake USING INTEGER PRIMARY KEY (rowid=?)",
+                PlanNodeKind::IndexScan,
+            ),
+            (
+                "SEARCH fruit USING COVERING INDEX idx_cake_id (cake_id=?)",
+                PlanNodeKind::IndexOnlyScan,
+            ),
+            ("USE TEMP B-TREE FOR ORDER BY", PlanNodeKind::Sort),
+            ("USE TEMP B-TREE FOR DISTINCT", PlanNodeKind::Other),
+        ] {
+            assert_eq!(sqlite_node(detail).kind, kind, "{detail}");
+        }
+    }
+}
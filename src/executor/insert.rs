@@ -1,8 +1,13 @@
 use crate::{
-    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, Insert, IntoActiveModel,
-    Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, SelectModel, SelectorRaw, Statement, TryFromU64,
+    error::*, query::soft_delete::find_with_deleted_by_id, ActiveModelTrait, ColumnTrait,
+    ConnectionTrait, EntityTrait, Insert, IntoActiveModel, Iterable, PrimaryKeyToColumn,
+    PrimaryKeyTrait, QueryFilter, QueryOrder, SelectModel, SelectorRaw, Statement,
+    TransactionTrait, TryFromU64,
+};
+use sea_query::{
+    Expr, FromValueTuple, Iden, InsertStatement, IntoColumnRef, IntoValueTuple, Query, SimpleExpr,
+    ValueTuple,
 };
-use sea_query::{Expr, FromValueTuple, Iden, InsertStatement, IntoColumnRef, Query, ValueTuple};
 use std::{future::Future, marker::PhantomData};
 
 /// Defines a structure to perform INSERT operations in an ActiveModel
@@ -26,6 +31,20 @@ where
     pub last_insert_id: <<<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType,
 }
 
+/// The result of an INSERT operation executed in chunks
+#[derive(Debug)]
+pub struct InsertManyResult<A>
+where
+    A: ActiveModelTrait,
+{
+    /// The `last_insert_id` of the final chunk, `None` if there was nothing to insert
+    pub last_insert_id: Option<<<<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    /// The total number of rows affected by all chunks
+    pub rows_affected: u64,
+    /// The number of INSERT statements executed
+    pub chunks: usize,
+}
+
 impl<A> Insert<A>
 where
     A: ActiveModelTrait,
@@ -39,16 +58,46 @@ where
     {
         // so that self is dropped before entering await
         let mut query = self.query;
-        if db.support_returning() && <A::Entity as EntityTrait>::PrimaryKey::iter().count() > 0 {
-            let returning = Query::returning().exprs(
-                <A::Entity as EntityTrait>::PrimaryKey::iter()
-                    .map(|c| c.into_column().select_as(Expr::col(c.into_column_ref()))),
-            );
-            query.returning(returning);
-        }
+        returning_primary_key::<A, _>(&mut query, db);
         Inserter::<A>::new(self.primary_key, query).exec(db)
     }
 
+    /// Execute an insert operation as a series of statements, each binding no more
+    /// parameters than the database backend allows.
+    ///
+    /// The chunks are executed in order without a surrounding transaction, so rows inserted
+    /// by earlier chunks are kept if a later one fails. See [Insert::exec_chunked_in_transaction].
+    pub fn exec_chunked<'a, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<InsertManyResult<A>, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        let chunks = self.into_chunks(db.get_database_backend());
+        exec_insert_chunks::<A, _>(chunks, db)
+    }
+
+    /// Execute an insert operation as a series of statements like [Insert::exec_chunked],
+    /// all within a transaction which is rolled back if any of them fails
+    pub fn exec_chunked_in_transaction<'a, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<InsertManyResult<A>, DbErr>> + 'a
+    where
+        C: ConnectionTrait + TransactionTrait,
+        A: 'a,
+    {
+        let chunks = self.into_chunks(db.get_database_backend());
+        async move {
+            let txn = db.begin().await?;
+            let res = exec_insert_chunks::<A, _>(chunks, &txn).await?;
+            txn.commit().await?;
+            Ok(res)
+        }
+    }
+
     /// Execute an insert operation without returning (don't use `RETURNING` syntax)
     /// Number of rows affected is returned
     pub fn exec_without_returning<'a, C>(
@@ -75,6 +124,24 @@ where
     {
         Inserter::<A>::new(self.primary_key, self.query).exec_with_returning(db)
     }
+
+    /// Execute an insert operation and return all the inserted models (use `RETURNING` syntax if database supported).
+    ///
+    /// Without `RETURNING`, the inserted rows are selected afterwards by their primary keys,
+    /// and returned ordered by primary key. This requires the primary key of every row to be
+    /// set, otherwise [DbErr::BackendNotSupported] is returned without inserting anything.
+    pub fn exec_with_returning_many<'a, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>> + '_
+    where
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        let (key_columns, keys) = self.row_keys(db.get_database_backend());
+        let upsert = self.upsert_on.is_some();
+        exec_insert_with_returning_many::<A, _>(key_columns, keys, upsert, self.query, db)
+    }
 }
 
 impl<A> Inserter<A>
@@ -126,11 +193,69 @@ where
     }
 }
 
+fn returning_primary_key<A, C>(query: &mut InsertStatement, db: &C)
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    if db.support_returning() && <A::Entity as EntityTrait>::PrimaryKey::iter().count() > 0 {
+        let returning = Query::returning().exprs(
+            <A::Entity as EntityTrait>::PrimaryKey::iter()
+                .map(|c| c.into_column().select_as(Expr::col(c.into_column_ref()))),
+        );
+        query.returning(returning);
+    }
+}
+
+async fn exec_insert_chunks<A, C>(
+    chunks: Vec<(Option<ValueTuple>, InsertStatement)>,
+    db: &C,
+) -> Result<InsertManyResult<A>, DbErr>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    let db_backend = db.get_database_backend();
+    let mut result = InsertManyResult {
+        last_insert_id: None,
+        rows_affected: 0,
+        chunks: 0,
+    };
+    for (primary_key, mut query) in chunks {
+        returning_primary_key::<A, _>(&mut query, db);
+        let (last_insert_id, rows_affected) =
+            exec_insert_counted::<A, _>(primary_key, db_backend.build(&query), db).await?;
+        result.last_insert_id = Some(last_insert_id);
+        result.rows_affected += rows_affected;
+        result.chunks += 1;
+    }
+    Ok(result)
+}
+
 async fn exec_insert<A, C>(
     primary_key: Option<ValueTuple>,
     statement: Statement,
     db: &C,
 ) -> Result<InsertResult<A>, DbErr>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    let (last_insert_id, _) = exec_insert_counted::<A, _>(primary_key, statement, db).await?;
+    Ok(InsertResult { last_insert_id })
+}
+
+async fn exec_insert_counted<A, C>(
+    primary_key: Option<ValueTuple>,
+    statement: Statement,
+    db: &C,
+) -> Result<
+    (
+        <<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType,
+        u64,
+    ),
+    DbErr,
+>
 where
     C: ConnectionTrait,
     A: ActiveModelTrait,
@@ -138,16 +263,20 @@ where
     type PrimaryKey<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey;
     type ValueTypeOf<A> = <PrimaryKey<A> as PrimaryKeyTrait>::ValueType;
 
-    let last_insert_id = match (primary_key, db.support_returning()) {
+    match (primary_key, db.support_returning()) {
         (Some(value_tuple), _) => {
             let res = db.execute(statement).await?;
             if res.rows_affected() == 0 {
                 return Err(DbErr::RecordNotInserted);
             }
-            FromValueTuple::from_value_tuple(value_tuple)
+            Ok((
+                FromValueTuple::from_value_tuple(value_tuple),
+                res.rows_affected(),
+            ))
         }
         (None, true) => {
             let mut rows = db.query_all(statement).await?;
+            let rows_affected = rows.len() as u64;
             let row = match rows.pop() {
                 Some(row) => row,
                 None => return Err(DbErr::RecordNotInserted),
@@ -155,20 +284,21 @@ where
             let cols = PrimaryKey::<A>::iter()
                 .map(|col| col.to_string())
                 .collect::<Vec<_>>();
-            row.try_get_many("", cols.as_ref())
-                .map_err(|_| DbErr::UnpackInsertId)?
+            let last_insert_id = row
+                .try_get_many("", cols.as_ref())
+                .map_err(|_| DbErr::UnpackInsertId)?;
+            Ok((last_insert_id, rows_affected))
         }
         (None, false) => {
             let res = db.execute(statement).await?;
             if res.rows_affected() == 0 {
                 return Err(DbErr::RecordNotInserted);
             }
-            let last_insert_id = res.last_insert_id();
-            ValueTypeOf::<A>::try_from_u64(last_insert_id).map_err(|_| DbErr::UnpackInsertId)?
+            let last_insert_id = ValueTypeOf::<A>::try_from_u64(res.last_insert_id())
+                .map_err(|_| DbErr::UnpackInsertId)?;
+            Ok((last_insert_id, res.rows_affected()))
         }
-    };
-
-    Ok(InsertResult { last_insert_id })
+    }
 }
 
 async fn exec_insert_without_returning<C>(
@@ -209,7 +339,8 @@ where
         false => {
             let insert_res =
                 exec_insert::<A, _>(primary_key, db_backend.build(&insert_statement), db).await?;
-            <A::Entity as EntityTrait>::find_by_id(insert_res.last_insert_id)
+            // the inserted row is returned even if it is soft deleted
+            find_with_deleted_by_id::<A::Entity>(insert_res.last_insert_id.into_value_tuple())
                 .one(db)
                 .await?
         }
@@ -221,3 +352,391 @@ where
         )),
     }
 }
+
+async fn exec_insert_with_returning_many<A, C>(
+    key_columns: Vec<<A::Entity as EntityTrait>::Column>,
+    keys: Vec<Option<ValueTuple>>,
+    upsert: bool,
+    mut insert_statement: InsertStatement,
+    db: &C,
+) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    type Entity<A> = <A as ActiveModelTrait>::Entity;
+
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+    let db_backend = db.get_database_backend();
+    if db.support_returning() {
+        let returning = Query::returning()
+            .exprs(<Entity<A> as EntityTrait>::Column::iter().map(|c| c.select_as(Expr::col(c))));
+        insert_statement.returning(returning);
+        return SelectorRaw::<SelectModel<<Entity<A> as EntityTrait>::Model>>::from_statement(
+            db_backend.build(&insert_statement),
+        )
+        .all(db)
+        .await;
+    }
+
+    // without `RETURNING`, the rows can only be selected back by keys known up front
+    let cols = key_columns;
+    let keys = match keys.into_iter().collect::<Option<Vec<_>>>() {
+        Some(keys) if !cols.is_empty() => keys,
+        _ => {
+            return Err(DbErr::BackendNotSupported {
+                db: db_backend.as_str(),
+                ctx: "INSERT RETURNING of generated primary keys",
+            })
+        }
+    };
+    let res = db.execute(db_backend.build(&insert_statement)).await?;
+    // an upsert leaving the existing rows unchanged affects no rows on MySQL
+    if res.rows_affected() == 0 && !upsert {
+        return Err(DbErr::RecordNotInserted);
+    }
+    // the inserted rows are returned even if they are soft deleted
+    let mut select = <Entity<A> as EntityTrait>::with_deleted();
+    match cols.as_slice() {
+        [col] => select = select.filter(col.is_in(keys.into_iter().flatten())),
+        _ => {
+            let tuple = cols
+                .iter()
+                .map(|col| SimpleExpr::Column((col.entity_name(), *col).into_column_ref()));
+            select = select.filter(Expr::tuple(tuple).in_tuples(keys));
+        }
+    }
+    for col in cols {
+        select = select.order_by_asc(col);
+    }
+    select.all(db).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+    use sea_query::OnConflict;
+
+    #[smol_potat::test]
+    async fn insert_many_with_returning() -> Result<(), DbErr> {
+        let cakes = vec![
+            cake::Model {
+                id: 1,
+                name: "Apple Pie".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "Orange Scone".to_owned(),
+            },
+        ];
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([cakes.clone()])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::insert_many([
+                cake::ActiveModel {
+                    name: Set("Apple Pie".to_owned()),
+                    ..Default::default()
+                },
+                cake::ActiveModel {
+                    name: Set("Orange Scone".to_owned()),
+                    ..Default::default()
+                },
+            ])
+            .exec_with_returning_many(&db)
+            .await?,
+            cakes
+        );
+
+        assert_eq!(
+            cake::Entity::insert_many(Vec::<cake::ActiveModel>::new())
+                .exec_with_returning_many(&db)
+                .await?,
+            []
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" ("name") VALUES ($1), ($2) RETURNING "id", "name""#,
+                ["Apple Pie".into(), "Orange Scone".into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_many_with_returning_fallback() -> Result<(), DbErr> {
+        let cake_fillings = vec![
+            cake_filling::Model {
+                cake_id: 11,
+                filling_id: 1,
+            },
+            cake_filling::Model {
+                cake_id: 12,
+                filling_id: 2,
+            },
+        ];
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .append_query_results([cake_fillings.clone()])
+            .into_connection();
+
+        assert!(matches!(
+            cake::Entity::insert_many([
+                cake::ActiveModel {
+                    name: Set("Apple Pie".to_owned()),
+                    ..Default::default()
+                },
+                cake::ActiveModel {
+                    name: Set("Orange Scone".to_owned()),
+                    ..Default::default()
+                },
+            ])
+            .exec_with_returning_many(&db)
+            .await,
+            Err(DbErr::BackendNotSupported { .. })
+        ));
+
+        assert_eq!(
+            cake_filling::Entity::insert_many(
+                cake_fillings
+                    .iter()
+                    .cloned()
+                    .map(IntoActiveModel::into_active_model)
+            )
+            .exec_with_returning_many(&db)
+            .await?,
+            cake_fillings
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"INSERT INTO `cake_filling` (`cake_id`, `filling_id`) VALUES (?, ?), (?, ?)"#,
+                    [11i32.into(), 1i32.into(), 12i32.into(), 2i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    [
+                        r#"SELECT `cake_filling`.`cake_id`, `cake_filling`.`filling_id` FROM `cake_filling`"#,
+                        r#"WHERE (`cake_filling`.`cake_id`, `cake_filling`.`filling_id`) IN ((?, ?), (?, ?))"#,
+                        r#"ORDER BY `cake_filling`.`cake_id` ASC, `cake_filling`.`filling_id` ASC"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [11i32.into(), 1i32.into(), 12i32.into(), 2i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_chunked() -> Result<(), DbErr> {
+        let cakes: Vec<_> = (1..=40_000)
+            .map(|id| {
+                cake::Model {
+                    id,
+                    name: format!("Cake #{id}"),
+                }
+                .into_active_model()
+            })
+            .collect();
+        let (first, second) = cakes.split_at(65535 / 2);
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 1,
+                    rows_affected: first.len() as u64,
+                },
+                MockExecResult {
+                    last_insert_id: 32_768,
+                    rows_affected: second.len() as u64,
+                },
+            ])
+            .into_connection();
+
+        // changes made to the statement are kept in every chunk
+        let on_conflict = OnConflict::column(cake::Column::Name)
+            .update_column(cake::Column::Name)
+            .to_owned();
+        let mut insert = cake::Entity::insert_many(cakes.clone());
+        QueryTrait::query(&mut insert).on_conflict(on_conflict.clone());
+        let res = insert.exec_chunked(&db).await?;
+
+        assert_eq!(res.last_insert_id, Some(32_768));
+        assert_eq!(res.rows_affected, 40_000);
+        assert_eq!(res.chunks, 2);
+        assert_eq!(
+            db.into_transaction_log(),
+            Transaction::wrap([
+                Insert::many(first.to_vec())
+                    .on_conflict(on_conflict.clone())
+                    .build(DbBackend::MySql),
+                Insert::many(second.to_vec())
+                    .on_conflict(on_conflict)
+                    .build(DbBackend::MySql),
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_chunked_max_bind_params() -> Result<(), DbErr> {
+        let cakes: Vec<_> = (1..=1000)
+            .map(|id| {
+                cake::Model {
+                    id,
+                    name: format!("Cake #{id}"),
+                }
+                .into_active_model()
+            })
+            .collect();
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results((1..=3).map(|_| MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }))
+            .into_connection();
+
+        // the value bound by ON CONFLICT leaves room for 499 rows of 2 values each
+        let on_conflict = OnConflict::column(cake::Column::Name)
+            .update_value((cake::Column::Name, "Cake".into()))
+            .to_owned();
+        let res = cake::Entity::insert_many(cakes.clone())
+            .on_conflict(on_conflict.clone())
+            .max_bind_params(1000)
+            .exec_chunked(&db)
+            .await?;
+
+        assert_eq!(res.chunks, 3);
+        let chunk = |rows: &[cake::ActiveModel]| {
+            Insert::many(rows.to_vec())
+                .on_conflict(on_conflict.clone())
+                .build(DbBackend::Sqlite)
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            Transaction::wrap([
+                chunk(&cakes[..499]),
+                chunk(&cakes[499..998]),
+                chunk(&cakes[998..]),
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_chunked_in_transaction() -> Result<(), DbErr> {
+        let cakes: Vec<_> = (1..=70_000)
+            .map(|id| cake::ActiveModel {
+                name: Set(format!("Cake #{id}")),
+                ..Default::default()
+            })
+            .collect();
+        let (first, second) = cakes.split_at(65535);
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 65535,
+                name: "Cake #65535".to_owned(),
+            }]])
+            .append_query_errors([DbErr::Custom("chunk failed".to_owned())])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::insert_many(cakes.clone())
+                .exec_chunked_in_transaction(&db)
+                .await
+                .map(|res| res.rows_affected),
+            Err(DbErr::Custom("chunk failed".to_owned()))
+        );
+
+        let returning = |models: &[cake::ActiveModel]| {
+            let mut query = Insert::many(models.to_vec()).into_query();
+            query.returning_col(cake::Column::Id);
+            DbBackend::Postgres.build(&query)
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                returning(first),
+                returning(second),
+                Statement::from_string(DbBackend::Postgres, "ROLLBACK".to_owned()),
+            ])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn upsert_on_unique() -> Result<(), DbErr> {
+        let cakes = vec![
+            cake::Model {
+                id: 3,
+                name: "Apple Pie".to_owned(),
+            },
+            cake::Model {
+                id: 7,
+                name: "Orange Scone".to_owned(),
+            },
+        ];
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .append_query_results([cakes.clone()])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::insert_many(cakes.iter().map(|cake| cake::ActiveModel {
+                name: Set(cake.name.clone()),
+                ..Default::default()
+            }))
+            .upsert_on([cake::Column::Name])
+            .exec_with_returning_many(&db)
+            .await?,
+            cakes
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"INSERT INTO `cake` (`name`) VALUES (?), (?) ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)"#,
+                    ["Apple Pie".into(), "Orange Scone".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` IN (?, ?) ORDER BY `cake`.`name` ASC"#,
+                    ["Apple Pie".into(), "Orange Scone".into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
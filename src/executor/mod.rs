@@ -1,6 +1,7 @@
 mod cursor;
 mod delete;
 mod execute;
+mod explain;
 mod insert;
 mod paginator;
 mod query;
@@ -10,6 +11,7 @@ mod update;
 pub use cursor::*;
 pub use delete::*;
 pub use execute::*;
+pub use explain::*;
 pub use insert::*;
 pub use paginator::*;
 pub use query::*;
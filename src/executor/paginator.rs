@@ -1,11 +1,13 @@
+use super::select::consolidate_models;
 use crate::{
-    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, Select, SelectModel,
-    SelectTwo, SelectTwoModel, Selector, SelectorRaw, SelectorTrait,
+    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, IdenStatic, Iterable,
+    ModelTrait, PrimaryKeyToColumn, Select, SelectA, SelectModel, SelectTwo, SelectTwoMany,
+    SelectTwoManyModel, SelectTwoModel, Selector, SelectorRaw, SelectorTrait,
 };
 use async_stream::stream;
 use futures::Stream;
-use sea_query::{Alias, Expr, SelectStatement};
-use std::{marker::PhantomData, pin::Pin};
+use sea_query::{Alias, Expr, SelectStatement, SimpleExpr};
+use std::{fmt, marker::PhantomData, pin::Pin};
 
 /// Pin a Model so that stream operations can be performed on the model
 pub type PinBoxStream<'db, Item> = Pin<Box<dyn Stream<Item = Item> + 'db>>;
@@ -22,6 +24,69 @@ where
     pub(crate) page_size: u64,
     pub(crate) db: &'db C,
     pub(crate) selector: PhantomData<S>,
+    pub(crate) children: Option<PaginatorChildren<S>>,
+}
+
+/// Fetches the rows of a page of parents along with their children,
+/// when paginating a one-to-many select by its parents
+pub(crate) struct PaginatorChildren<S>
+where
+    S: SelectorTrait,
+{
+    /// The parents joined with their children
+    pub(crate) query: SelectStatement,
+    /// The primary key of the parents, and its aliases in the paginated query
+    pub(crate) primary_key: Vec<(SimpleExpr, String)>,
+    /// Merges the children of the same parent
+    pub(crate) consolidate: fn(Vec<S::Item>) -> Vec<S::Item>,
+}
+
+impl<S> PaginatorChildren<S>
+where
+    S: SelectorTrait,
+{
+    /// Select the rows of the parents on the given page of the paginated query
+    fn page_query(&self, page: SelectStatement) -> SelectStatement {
+        let mut keys = SelectStatement::new();
+        for (_, alias) in self.primary_key.iter() {
+            keys.expr(Expr::col((Alias::new("page"), Alias::new(alias))));
+        }
+        keys.from_subquery(page, Alias::new("page"));
+
+        let primary_key = match self.primary_key.as_slice() {
+            [(col, _)] => Expr::expr(col.clone()),
+            cols => Expr::tuple(cols.iter().map(|(col, _)| col.clone())),
+        };
+        self.query
+            .clone()
+            .and_where(primary_key.in_subquery(keys))
+            .to_owned()
+    }
+}
+
+impl<S> Clone for PaginatorChildren<S>
+where
+    S: SelectorTrait,
+{
+    fn clone(&self) -> Self {
+        Self {
+            query: self.query.clone(),
+            primary_key: self.primary_key.clone(),
+            consolidate: self.consolidate,
+        }
+    }
+}
+
+impl<S> fmt::Debug for PaginatorChildren<S>
+where
+    S: SelectorTrait,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PaginatorChildren")
+            .field("query", &self.query)
+            .field("primary_key", &self.primary_key)
+            .finish()
+    }
 }
 
 /// Define a structure containing the numbers of items and pages of a Paginator
@@ -42,12 +107,15 @@ where
 {
     /// Fetch a specific page; page index starts from zero
     pub async fn fetch_page(&self, page: u64) -> Result<Vec<S::Item>, DbErr> {
-        let query = self
+        let mut query = self
             .query
             .clone()
             .limit(self.page_size)
             .offset(self.page_size * page)
             .to_owned();
+        if let Some(children) = &self.children {
+            query = children.page_query(query);
+        }
         let builder = self.db.get_database_backend();
         let stmt = builder.build(&query);
         let rows = self.db.query_all(stmt).await?;
@@ -56,6 +124,9 @@ where
             // TODO: Error handling
             buffer.push(S::from_raw_query_result(row)?);
         }
+        if let Some(children) = &self.children {
+            buffer = (children.consolidate)(buffer);
+        }
         Ok(buffer)
     }
 
@@ -211,6 +282,7 @@ where
     type Selector: SelectorTrait + Send + Sync + 'db;
 
     /// Paginate the result of a select operation.
+    /// A [SelectTwoMany] is paginated by its parents, which it must only be ordered by.
     fn paginate(self, db: &'db C, page_size: u64) -> Paginator<'db, C, Self::Selector>;
 
     /// Perform a count on the paginated results
@@ -237,6 +309,7 @@ where
             page_size,
             db,
             selector: PhantomData,
+            children: None,
         }
     }
 }
@@ -263,6 +336,7 @@ where
             page_size,
             db,
             selector: PhantomData,
+            children: None,
         }
     }
 }
@@ -295,6 +369,56 @@ where
     }
 }
 
+impl<'db, C, M, N, E, F> PaginatorTrait<'db, C> for SelectTwoMany<E, F>
+where
+    C: ConnectionTrait,
+    E: EntityTrait<Model = M>,
+    F: EntityTrait<Model = N>,
+    M: FromQueryResult + ModelTrait + Sized + Send + Sync + 'db,
+    N: FromQueryResult + Sized + Send + Sync + 'db,
+{
+    type Selector = SelectTwoManyModel<M, N>;
+
+    /// Paginate the parents, along with all of their children: the pages are fetched in two
+    /// stages, first the parents of the page, then their rows joined with the children.
+    ///
+    /// The parents are grouped by their primary key to find the ones of a page, so the query must
+    /// only be ordered by the columns of the parent. Ordering it by a column of the children
+    /// results in invalid SQL under `GROUP BY`, an error once a page is fetched.
+    fn paginate(self, db: &'db C, page_size: u64) -> Paginator<'db, C, Self::Selector> {
+        assert!(page_size != 0, "page_size should not be zero");
+        let primary_key: Vec<(SimpleExpr, String)> = <E::PrimaryKey as Iterable>::iter()
+            .map(|pk| {
+                let col = pk.into_column();
+                (
+                    Expr::col((E::default(), col)).into(),
+                    format!("{}{}", SelectA.as_str(), col.as_str()),
+                )
+            })
+            .collect();
+
+        let mut parents = self.query.clone();
+        parents.clear_selects();
+        for (col, alias) in primary_key.iter() {
+            parents.expr_as(col.clone(), Alias::new(alias));
+        }
+        parents.add_group_by(primary_key.iter().map(|(col, _)| col.clone()));
+
+        Paginator {
+            query: parents,
+            page: 0,
+            page_size,
+            db,
+            selector: PhantomData,
+            children: Some(PaginatorChildren {
+                query: self.query,
+                primary_key,
+                consolidate: consolidate_models::<M, N>,
+            }),
+        }
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "mock")]
 mod tests {
@@ -767,6 +891,102 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn fetch_page_with_related() -> Result<(), DbErr> {
+        let row = |cake_id: i32, cake_name: &str, fruit: Option<(i32, &str)>| {
+            let (fruit_id, fruit_name) = match fruit {
+                Some((id, name)) => (Some(id), Some(name.to_owned())),
+                None => (None, None),
+            };
+            maplit::btreemap! {
+                "A_id" => Into::<Value>::into(cake_id),
+                "A_name" => cake_name.into(),
+                "B_id" => fruit_id.into(),
+                "B_name" => fruit_name.into(),
+                "B_cake_id" => fruit_id.map(|_| cake_id).into(),
+            }
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                vec![
+                    row(1, "Cheese Cake", Some((1, "Blueberry"))),
+                    row(1, "Cheese Cake", Some((2, "Rasberry"))),
+                    row(1, "Cheese Cake", Some((3, "Strawberry"))),
+                    row(2, "Chocolate Cake", None),
+                ],
+                vec![],
+            ])
+            .append_query_results([[maplit::btreemap! {
+                "num_items" => Into::<Value>::into(3i64),
+            }]])
+            .into_connection();
+
+        let paginator = cake::Entity::find()
+            .find_with_related(fruit::Entity)
+            .paginate(&db, 2);
+
+        let fruit = |id: i32, name: &str| fruit::Model {
+            id,
+            name: name.to_owned(),
+            cake_id: Some(1),
+        };
+        assert_eq!(
+            paginator.fetch_page(0).await?,
+            [
+                (
+                    cake::Model {
+                        id: 1,
+                        name: "Cheese Cake".to_owned(),
+                    },
+                    vec![
+                        fruit(1, "Blueberry"),
+                        fruit(2, "Rasberry"),
+                        fruit(3, "Strawberry")
+                    ]
+                ),
+                (
+                    cake::Model {
+                        id: 2,
+                        name: "Chocolate Cake".to_owned(),
+                    },
+                    vec![]
+                ),
+            ]
+        );
+        assert_eq!(paginator.fetch_page(1).await?, []);
+        assert_eq!(paginator.num_items().await?, 3);
+
+        let page = |offset: u64| {
+            let sql = [
+                r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name", "fruit"."cake_id" AS "B_cake_id""#,
+                r#"FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                r#"WHERE "cake"."id" IN (SELECT "page"."A_id" FROM"#,
+                r#"(SELECT "cake"."id" AS "A_id" FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                r#"GROUP BY "cake"."id" ORDER BY "cake"."id" ASC LIMIT $1 OFFSET $2) AS "page")"#,
+                r#"ORDER BY "cake"."id" ASC"#,
+            ]
+            .join(" ");
+            Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                &sql,
+                [2u64.into(), offset.into()],
+            )
+        };
+        let count = Transaction::from_sql_and_values(
+            DbBackend::Postgres,
+            &[
+                r#"SELECT COUNT(*) AS num_items FROM"#,
+                r#"(SELECT "cake"."id" AS "A_id" FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                r#"GROUP BY "cake"."id" ORDER BY "cake"."id" ASC) AS "sub_query""#,
+            ]
+            .join(" "),
+            [],
+        );
+        assert_eq!(db.into_transaction_log(), [page(0), page(2), count]);
+        Ok(())
+    }
+
     #[smol_potat::test]
     #[should_panic]
     async fn error() {
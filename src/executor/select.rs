@@ -1,6 +1,8 @@
+use super::explain::explain;
 use crate::{
     error::*, ConnectionTrait, EntityTrait, FromQueryResult, IdenStatic, Iterable, ModelTrait,
-    PrimaryKeyToColumn, QueryResult, Select, SelectA, SelectB, SelectTwo, SelectTwoMany, Statement,
+    PartialModelTrait, PrimaryKeyToColumn, QueryPlan, QueryResult, QuerySelect, Select, SelectA,
+    SelectB, SelectC, SelectD, SelectFour, SelectThree, SelectTwo, SelectTwoMany, Statement,
     StreamTrait, TryGetableMany,
 };
 use futures::{Stream, TryStreamExt};
@@ -80,6 +82,41 @@ where
     model: PhantomData<(M, N)>,
 }
 
+/// Defines a type to get three Models
+#[derive(Clone, Debug)]
+pub struct SelectThreeModel<M, N, O>
+where
+    M: FromQueryResult,
+    N: FromQueryResult,
+    O: FromQueryResult,
+{
+    model: PhantomData<(M, N, O)>,
+}
+
+/// Defines a type to get four Models
+#[derive(Clone, Debug)]
+pub struct SelectFourModel<M, N, O, P>
+where
+    M: FromQueryResult,
+    N: FromQueryResult,
+    O: FromQueryResult,
+    P: FromQueryResult,
+{
+    model: PhantomData<(M, N, O, P)>,
+}
+
+/// Defines a type to get a Model along with its related Models, one row at a time.
+/// The rows of the same Model are merged together by [Paginator](crate::Paginator)
+/// when paginating a [SelectTwoMany].
+#[derive(Clone, Debug)]
+pub struct SelectTwoManyModel<M, N>
+where
+    M: FromQueryResult,
+    N: FromQueryResult,
+{
+    model: PhantomData<(M, N)>,
+}
+
 impl<T, C> SelectorTrait for SelectGetableValue<T, C>
 where
     T: TryGetableMany,
@@ -130,6 +167,59 @@ where
     }
 }
 
+impl<M, N, O> SelectorTrait for SelectThreeModel<M, N, O>
+where
+    M: FromQueryResult + Sized,
+    N: FromQueryResult + Sized,
+    O: FromQueryResult + Sized,
+{
+    type Item = (M, Option<N>, Option<O>);
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        Ok((
+            M::from_query_result(&res, SelectA.as_str())?,
+            N::from_query_result_optional(&res, SelectB.as_str())?,
+            O::from_query_result_optional(&res, SelectC.as_str())?,
+        ))
+    }
+}
+
+impl<M, N, O, P> SelectorTrait for SelectFourModel<M, N, O, P>
+where
+    M: FromQueryResult + Sized,
+    N: FromQueryResult + Sized,
+    O: FromQueryResult + Sized,
+    P: FromQueryResult + Sized,
+{
+    type Item = (M, Option<N>, Option<O>, Option<P>);
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        Ok((
+            M::from_query_result(&res, SelectA.as_str())?,
+            N::from_query_result_optional(&res, SelectB.as_str())?,
+            O::from_query_result_optional(&res, SelectC.as_str())?,
+            P::from_query_result_optional(&res, SelectD.as_str())?,
+        ))
+    }
+}
+
+impl<M, N> SelectorTrait for SelectTwoManyModel<M, N>
+where
+    M: FromQueryResult + Sized,
+    N: FromQueryResult + Sized,
+{
+    type Item = (M, Vec<N>);
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        Ok((
+            M::from_query_result(&res, SelectA.as_str())?,
+            N::from_query_result_optional(&res, SelectB.as_str())?
+                .into_iter()
+                .collect(),
+        ))
+    }
+}
+
 impl<E> Select<E>
 where
     E: EntityTrait,
@@ -154,6 +244,15 @@ where
         }
     }
 
+    /// Select only the columns of the partial Model, and return a [Selector] that wraps a [SelectModel] of it.
+    /// See [DerivePartialModel](crate::DerivePartialModel).
+    pub fn into_partial_model<M>(self) -> Selector<SelectModel<M>>
+    where
+        M: PartialModelTrait,
+    {
+        M::select_cols(QuerySelect::select_only(self)).into_model::<M>()
+    }
+
     /// Get a selectable Model as a [JsonValue] for SQL JSON operations
     #[cfg(feature = "with-json")]
     pub fn into_json(self) -> Selector<SelectModel<JsonValue>> {
@@ -397,6 +496,23 @@ where
     {
         self.into_model().stream(db).await
     }
+
+    /// Get the query plan of the SELECT query from `EXPLAIN`, see [QueryPlan]
+    pub async fn explain<C>(self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model>().explain(db).await
+    }
+
+    /// Run the SELECT query with `EXPLAIN ANALYZE` to get its query plan along with the actual
+    /// number of rows of each step. This is not supported on SQLite.
+    pub async fn explain_analyze<C>(self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model>().explain_analyze(db).await
+    }
 }
 
 impl<E, F> SelectTwo<E, F>
@@ -451,6 +567,185 @@ where
     {
         self.into_model().stream(db).await
     }
+
+    /// Get the query plan of the SELECT query from `EXPLAIN`, see [QueryPlan]
+    pub async fn explain<C>(self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model, F::Model>().explain(db).await
+    }
+
+    /// Run the SELECT query with `EXPLAIN ANALYZE` to get its query plan along with the actual
+    /// number of rows of each step. This is not supported on SQLite.
+    pub async fn explain_analyze<C>(self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model, F::Model>()
+            .explain_analyze(db)
+            .await
+    }
+}
+
+impl<E, F, G> SelectThree<E, F, G>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+    G: EntityTrait,
+{
+    /// Perform a conversion into a [SelectThreeModel]
+    pub fn into_model<M, N, O>(self) -> Selector<SelectThreeModel<M, N, O>>
+    where
+        M: FromQueryResult,
+        N: FromQueryResult,
+        O: FromQueryResult,
+    {
+        Selector {
+            query: self.query,
+            selector: SelectThreeModel { model: PhantomData },
+        }
+    }
+
+    /// Convert the Models into JsonValue
+    #[cfg(feature = "with-json")]
+    pub fn into_json(self) -> Selector<SelectThreeModel<JsonValue, JsonValue, JsonValue>> {
+        Selector {
+            query: self.query,
+            selector: SelectThreeModel { model: PhantomData },
+        }
+    }
+
+    /// Get one Model from the Select query
+    pub async fn one<'a, C>(
+        self,
+        db: &C,
+    ) -> Result<Option<(E::Model, Option<F::Model>, Option<G::Model>)>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model().one(db).await
+    }
+
+    /// Get all Models from the Select query
+    pub async fn all<'a, C>(
+        self,
+        db: &C,
+    ) -> Result<Vec<(E::Model, Option<F::Model>, Option<G::Model>)>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model().all(db).await
+    }
+
+    /// Stream the results of a Select operation on a Model
+    pub async fn stream<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+    ) -> Result<
+        impl Stream<Item = Result<(E::Model, Option<F::Model>, Option<G::Model>), DbErr>> + 'b,
+        DbErr,
+    >
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+    {
+        self.into_model().stream(db).await
+    }
+}
+
+impl<E, F, G, H> SelectFour<E, F, G, H>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+    G: EntityTrait,
+    H: EntityTrait,
+{
+    /// Perform a conversion into a [SelectFourModel]
+    pub fn into_model<M, N, O, P>(self) -> Selector<SelectFourModel<M, N, O, P>>
+    where
+        M: FromQueryResult,
+        N: FromQueryResult,
+        O: FromQueryResult,
+        P: FromQueryResult,
+    {
+        Selector {
+            query: self.query,
+            selector: SelectFourModel { model: PhantomData },
+        }
+    }
+
+    /// Convert the Models into JsonValue
+    #[cfg(feature = "with-json")]
+    pub fn into_json(
+        self,
+    ) -> Selector<SelectFourModel<JsonValue, JsonValue, JsonValue, JsonValue>> {
+        Selector {
+            query: self.query,
+            selector: SelectFourModel { model: PhantomData },
+        }
+    }
+
+    /// Get one Model from the Select query
+    pub async fn one<'a, C>(
+        self,
+        db: &C,
+    ) -> Result<
+        Option<(
+            E::Model,
+            Option<F::Model>,
+            Option<G::Model>,
+            Option<H::Model>,
+        )>,
+        DbErr,
+    >
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model().one(db).await
+    }
+
+    /// Get all Models from the Select query
+    pub async fn all<'a, C>(
+        self,
+        db: &C,
+    ) -> Result<
+        Vec<(
+            E::Model,
+            Option<F::Model>,
+            Option<G::Model>,
+            Option<H::Model>,
+        )>,
+        DbErr,
+    >
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model().all(db).await
+    }
+
+    /// Stream the results of a Select operation on a Model
+    pub async fn stream<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+    ) -> Result<
+        impl Stream<
+                Item = Result<
+                    (
+                        E::Model,
+                        Option<F::Model>,
+                        Option<G::Model>,
+                        Option<H::Model>,
+                    ),
+                    DbErr,
+                >,
+            > + 'b,
+        DbErr,
+    >
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+    {
+        self.into_model().stream(db).await
+    }
 }
 
 impl<E, F> SelectTwoMany<E, F>
@@ -505,15 +800,6 @@ where
         let rows = self.into_model().all(db).await?;
         Ok(consolidate_query_result::<E, F>(rows))
     }
-
-    // pub fn paginate()
-    // we could not implement paginate easily, if the number of children for a
-    // parent is larger than one page, then we will end up splitting it in two pages
-    // so the correct way is actually perform query in two stages
-    // paginate the parent model and then populate the children
-
-    // pub fn count()
-    // we should only count the number of items of the parent model
 }
 
 impl<S> Selector<S>
@@ -588,6 +874,23 @@ where
     {
         self.into_selector_raw(db).stream(db).await
     }
+
+    /// Get the query plan of the SELECT query from `EXPLAIN`, see [QueryPlan]
+    pub async fn explain<C>(self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_selector_raw(db).explain(db).await
+    }
+
+    /// Run the SELECT query with `EXPLAIN ANALYZE` to get its query plan along with the actual
+    /// number of rows of each step. This is not supported on SQLite.
+    pub async fn explain_analyze<C>(self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_selector_raw(db).explain_analyze(db).await
+    }
 }
 
 impl<S> SelectorRaw<S>
@@ -877,6 +1180,23 @@ where
             futures::future::ready(S::from_raw_query_result(row))
         })))
     }
+
+    /// Get the query plan of the statement from `EXPLAIN`, see [QueryPlan]
+    pub async fn explain<C>(self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        explain(db, self.stmt, false).await
+    }
+
+    /// Run the statement with `EXPLAIN ANALYZE` to get its query plan along with the actual
+    /// number of rows of each step. This is not supported on SQLite.
+    pub async fn explain_analyze<C>(self, db: &C) -> Result<QueryPlan, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        explain(db, self.stmt, true).await
+    }
 }
 
 fn consolidate_query_result<L, R>(
@@ -886,11 +1206,23 @@ where
     L: EntityTrait,
     R: EntityTrait,
 {
-    let mut acc: Vec<(L::Model, Vec<R::Model>)> = Vec::new();
+    consolidate_models(
+        rows.into_iter()
+            .map(|(l, r)| (l, r.into_iter().collect()))
+            .collect(),
+    )
+}
+
+/// Merge the related Models of consecutive rows of the same Model
+pub(crate) fn consolidate_models<M, N>(rows: Vec<(M, Vec<N>)>) -> Vec<(M, Vec<N>)>
+where
+    M: ModelTrait,
+{
+    let mut acc: Vec<(M, Vec<N>)> = Vec::new();
     for (l, r) in rows {
         if let Some((last_l, last_r)) = acc.last_mut() {
             let mut same_l = true;
-            for pk_col in <L::PrimaryKey as Iterable>::iter() {
+            for pk_col in <<M::Entity as EntityTrait>::PrimaryKey as Iterable>::iter() {
                 let col = pk_col.into_column();
                 let val = l.get(col);
                 let last_val = last_l.get(col);
@@ -900,17 +1232,207 @@ where
                 }
             }
             if same_l {
-                if let Some(r) = r {
-                    last_r.push(r);
-                    continue;
-                }
+                last_r.extend(r);
+                continue;
             }
         }
-        let rows = match r {
-            Some(r) => vec![r],
-            None => vec![],
-        };
-        acc.push((l, rows));
+        acc.push((l, r));
     }
     acc
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate as sea_orm;
+    use crate::tests_cfg::*;
+    use crate::{
+        entity::*, error::*, query::*, DbBackend, FromQueryResult, MockDatabase, RelationTrait,
+        Statement, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+    use sea_query::{Expr, JoinType, Value};
+
+    #[smol_potat::test]
+    async fn select_four() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "A_id" => Into::<Value>::into(1),
+                "A_name" => "Cheese Cake".into(),
+                "B_id" => 2.into(),
+                "B_name" => "Rasberry".into(),
+                "B_cake_id" => 1.into(),
+                "C_id" => 3.into(),
+                "C_name" => "Cream".into(),
+                "C_vendor_id" => Value::Int(None),
+                "D_id" => Value::Int(None),
+                "D_name" => Value::String(None),
+            }]])
+            .into_connection();
+
+        let select = cake::Entity::find()
+            .left_join(fruit::Entity)
+            .select_also(fruit::Entity)
+            .join(JoinType::LeftJoin, cake_filling::Relation::Cake.def().rev())
+            .join(JoinType::LeftJoin, cake_filling::Relation::Filling.def())
+            .select_also(filling::Entity)
+            .join(JoinType::LeftJoin, filling::Relation::Vendor.def())
+            .select_also(vendor::Entity);
+        let stmt = select.build(DbBackend::Postgres);
+
+        assert_eq!(
+            select.all(&db).await?,
+            [(
+                cake::Model {
+                    id: 1,
+                    name: "Cheese Cake".to_owned(),
+                },
+                Some(fruit::Model {
+                    id: 2,
+                    name: "Rasberry".to_owned(),
+                    cake_id: Some(1),
+                }),
+                Some(filling::Model {
+                    id: 3,
+                    name: "Cream".to_owned(),
+                    vendor_id: None,
+                    ignored_attr: 0,
+                }),
+                None,
+            )]
+        );
+        assert_eq!(db.into_transaction_log(), [Transaction::one(stmt)]);
+
+        Ok(())
+    }
+
+    #[derive(Debug, PartialEq, sea_orm::DerivePartialModel, sea_orm::FromQueryResult)]
+    #[sea_orm(entity = "cake::Entity")]
+    struct PartialCake {
+        name: String,
+        #[sea_orm(from_col = "id")]
+        cake_id: i32,
+        #[sea_orm(entity = "fruit::Entity", from_col = "name")]
+        fruit_name: Option<String>,
+        #[sea_orm(from_expr = "Expr::col((cake::Entity, cake::Column::Id)).add(1)")]
+        next_id: i32,
+        #[sea_orm(skip)]
+        visited: bool,
+    }
+
+    #[smol_potat::test]
+    async fn into_partial_model() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "name" => Into::<Value>::into("Cheese Cake"),
+                "cake_id" => 1.into(),
+                "fruit_name" => "Blueberry".into(),
+                "next_id" => 2.into(),
+            }]])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::find()
+                .left_join(fruit::Entity)
+                .into_partial_model::<PartialCake>()
+                .all(&db)
+                .await?,
+            [PartialCake {
+                name: "Cheese Cake".to_owned(),
+                cake_id: 1,
+                fruit_name: Some("Blueberry".to_owned()),
+                next_id: 2,
+                visited: false,
+            }]
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."name" AS "name", "cake"."id" AS "cake_id","#,
+                    r#""fruit"."name" AS "fruit_name", "cake"."id" + $1 AS "next_id""#,
+                    r#"FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[derive(Debug, PartialEq, sea_orm::FromQueryResult)]
+    struct Fruit {
+        id: i32,
+        name: String,
+    }
+
+    #[derive(Debug, PartialEq, sea_orm::FromQueryResult)]
+    struct CakeWithFruit {
+        #[sea_orm(alias = "name")]
+        cake_name: String,
+        #[sea_orm(nested, prefix = "fruit_")]
+        fruit: Option<Fruit>,
+        #[sea_orm(nested, prefix = "fruit_")]
+        fruit_or_default: FruitOrDefault,
+        #[sea_orm(skip)]
+        visited: bool,
+    }
+
+    #[derive(Debug, PartialEq, sea_orm::FromQueryResult)]
+    struct FruitOrDefault {
+        #[sea_orm(alias = "id")]
+        fruit_id: Option<i32>,
+    }
+
+    #[smol_potat::test]
+    async fn from_query_result_attributes() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                maplit::btreemap! {
+                    "name" => Into::<Value>::into("Cheese Cake"),
+                    "fruit_id" => 1.into(),
+                    "fruit_name" => "Blueberry".into(),
+                },
+                maplit::btreemap! {
+                    "name" => Into::<Value>::into("Chocolate Cake"),
+                    "fruit_id" => Value::Int(None),
+                    "fruit_name" => Value::String(None),
+                },
+            ]])
+            .into_connection();
+
+        let stmt = Statement::from_string(
+            DbBackend::Postgres,
+            [
+                r#"SELECT "cake"."name", "fruit"."id" AS "fruit_id", "fruit"."name" AS "fruit_name""#,
+                r#"FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+            ]
+            .join(" "),
+        );
+        assert_eq!(
+            CakeWithFruit::find_by_statement(stmt).all(&db).await?,
+            [
+                CakeWithFruit {
+                    cake_name: "Cheese Cake".to_owned(),
+                    fruit: Some(Fruit {
+                        id: 1,
+                        name: "Blueberry".to_owned(),
+                    }),
+                    fruit_or_default: FruitOrDefault { fruit_id: Some(1) },
+                    visited: false,
+                },
+                CakeWithFruit {
+                    cake_name: "Chocolate Cake".to_owned(),
+                    fruit: None,
+                    fruit_or_default: FruitOrDefault { fruit_id: None },
+                    visited: false,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -1,14 +1,17 @@
 use crate::{
-    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel,
-    Iterable, PrimaryKeyTrait, SelectModel, SelectorRaw, UpdateMany, UpdateOne,
+    error::*,
+    query::{soft_delete::find_with_deleted_by_id, version_of},
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel, Iterable,
+    ModelTrait, SelectModel, SelectorRaw, UpdateMany, UpdateOne,
 };
-use sea_query::{Expr, FromValueTuple, Query, UpdateStatement};
+use sea_query::{Expr, Query, UpdateStatement};
 
 /// Defines an update operation
 #[derive(Clone, Debug)]
 pub struct Updater {
     query: UpdateStatement,
     check_record_exists: bool,
+    check_version: bool,
 }
 
 /// The result of an update operation on an ActiveModel
@@ -28,9 +31,11 @@ where
         <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
         C: ConnectionTrait,
     {
-        Updater::new(self.query)
-            .exec_update_and_return_updated(self.model, db)
-            .await
+        let mut updater = Updater::new(self.query);
+        if version_of(&self.model).is_some() {
+            updater = updater.check_version();
+        }
+        updater.exec_update_and_return_updated(self.model, db).await
     }
 }
 
@@ -43,7 +48,20 @@ where
     where
         C: ConnectionTrait,
     {
-        Updater::new(self.query).exec(db).await
+        Updater::new(self.prepare_auto_values().query)
+            .exec(db)
+            .await
+    }
+
+    /// Execute an update operation on multiple ActiveModels and return the updated models
+    /// (use `RETURNING` syntax, only if database supported)
+    pub async fn exec_with_returning<C>(self, db: &'a C) -> Result<Vec<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Updater::new(self.prepare_auto_values().query)
+            .exec_update_with_returning::<E, _>(db)
+            .await
     }
 }
 
@@ -53,6 +71,7 @@ impl Updater {
         Self {
             query,
             check_record_exists: false,
+            check_version: false,
         }
     }
 
@@ -62,6 +81,13 @@ impl Updater {
         self
     }
 
+    /// Treat an update affecting no rows of a record that still exists as a lost update on
+    /// a versioned record, resulting in [DbErr::RecordVersionMismatch]
+    pub(crate) fn check_version(mut self) -> Self {
+        self.check_version = true;
+        self
+    }
+
     /// Execute an update operation
     pub async fn exec<C>(self, db: &C) -> Result<UpdateResult, DbErr>
     where
@@ -95,7 +121,13 @@ impl Updater {
         type Column<A> = <Entity<A> as EntityTrait>::Column;
 
         if self.is_noop() {
-            return find_updated_model_by_id(model, db).await;
+            let version = version_of(&model).filter(|_| self.check_version);
+            let found = find_updated_model_by_id(model, db).await?;
+            // nothing is updated, but the record held may still be stale
+            return match version {
+                Some((col, value)) if found.get(col) != value => Err(DbErr::RecordVersionMismatch),
+                _ => Ok(found),
+            };
         }
 
         match db.support_returning() {
@@ -112,17 +144,49 @@ impl Updater {
                 // If we got `None` then we are updating a row that does not exist.
                 match found {
                     Some(model) => Ok(model),
+                    None if self.check_version => {
+                        Err(version_mismatch_or_not_found(model, db).await)
+                    }
                     None => Err(DbErr::RecordNotUpdated),
                 }
             }
             false => {
+                let check_version = self.check_version;
                 // If we updating a row that does not exist then an error will be thrown here.
-                self.check_record_exists().exec(db).await?;
-                find_updated_model_by_id(model, db).await
+                match self.check_record_exists().exec(db).await {
+                    Err(DbErr::RecordNotUpdated) if check_version => {
+                        Err(version_mismatch_or_not_found(model, db).await)
+                    }
+                    Err(err) => Err(err),
+                    Ok(_) => find_updated_model_by_id(model, db).await,
+                }
             }
         }
     }
 
+    async fn exec_update_with_returning<E, C>(mut self, db: &C) -> Result<Vec<E::Model>, DbErr>
+    where
+        E: EntityTrait,
+        C: ConnectionTrait,
+    {
+        if self.is_noop() {
+            return Ok(Vec::new());
+        }
+        let db_backend = db.get_database_backend();
+        if !db.support_returning() {
+            return Err(DbErr::BackendNotSupported {
+                db: db_backend.as_str(),
+                ctx: "UPDATE RETURNING",
+            });
+        }
+        let returning =
+            Query::returning().exprs(E::Column::iter().map(|c| c.select_as(Expr::col(c))));
+        self.query.returning(returning);
+        SelectorRaw::<SelectModel<E::Model>>::from_statement(db_backend.build(&self.query))
+            .all(db)
+            .await
+    }
+
     fn is_noop(&self) -> bool {
         self.query.get_values().is_empty()
     }
@@ -136,14 +200,14 @@ where
     A: ActiveModelTrait,
     C: ConnectionTrait,
 {
-    type Entity<A> = <A as ActiveModelTrait>::Entity;
-    type ValueType<A> = <<Entity<A> as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType;
-
     let primary_key_value = match model.get_primary_key_value() {
-        Some(val) => ValueType::<A>::from_value_tuple(val),
+        Some(val) => val,
         None => return Err(DbErr::UpdateGetPrimaryKey),
     };
-    let found = Entity::<A>::find_by_id(primary_key_value).one(db).await?;
+    // the updated row is returned even if it is soft deleted
+    let found = find_with_deleted_by_id::<A::Entity>(primary_key_value)
+        .one(db)
+        .await?;
     // If we cannot select the updated row from db by the cached primary key
     match found {
         Some(model) => Ok(model),
@@ -153,6 +217,27 @@ where
     }
 }
 
+/// The error of a versioned update affecting no rows: [DbErr::RecordVersionMismatch] if the
+/// record is still there, so its version has changed, or else [DbErr::RecordNotUpdated]
+async fn version_mismatch_or_not_found<A, C>(model: A, db: &C) -> DbErr
+where
+    A: ActiveModelTrait,
+    C: ConnectionTrait,
+{
+    let primary_key_value = match model.get_primary_key_value() {
+        Some(val) => val,
+        None => return DbErr::UpdateGetPrimaryKey,
+    };
+    match find_with_deleted_by_id::<A::Entity>(primary_key_value)
+        .one(db)
+        .await
+    {
+        Ok(Some(_)) => DbErr::RecordVersionMismatch,
+        Ok(None) => DbErr::RecordNotUpdated,
+        Err(err) => err,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{entity::prelude::*, tests_cfg::*, *};
@@ -314,4 +399,346 @@ mod tests {
 
         Ok(())
     }
+
+    mod versioned {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "post")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub title: String,
+            #[sea_orm(version)]
+            pub version: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[smol_potat::test]
+    async fn update_with_version() -> Result<(), DbErr> {
+        let model = versioned::Model {
+            id: 1,
+            title: "Hello".to_owned(),
+            version: 1,
+        };
+        let updated = versioned::Model {
+            title: "Hello World".to_owned(),
+            version: 2,
+            ..model.clone()
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([vec![updated.clone()], vec![], vec![updated.clone()]])
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 2,
+                },
+            ])
+            .into_connection();
+
+        assert_eq!(
+            versioned::ActiveModel {
+                title: Set("Hello World".to_owned()),
+                ..model.clone().into_active_model()
+            }
+            .update(&db)
+            .await?,
+            updated
+        );
+
+        assert_eq!(
+            versioned::ActiveModel {
+                title: Set("Hello World".to_owned()),
+                ..model.clone().into_active_model()
+            }
+            .update(&db)
+            .await,
+            Err(DbErr::RecordVersionMismatch)
+        );
+
+        assert_eq!(
+            model.clone().into_active_model().delete(&db).await,
+            Err(DbErr::RecordVersionMismatch)
+        );
+
+        assert_eq!(
+            versioned::Entity::update_many()
+                .col_expr(versioned::Column::Title, Expr::value("Hi"))
+                .exec(&db)
+                .await?,
+            UpdateResult { rows_affected: 2 }
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"UPDATE "post" SET "title" = $1, "version" = "version" + $2"#,
+                        r#"WHERE "post"."id" = $3 AND "post"."version" = $4"#,
+                        r#"RETURNING "id", "title", "version""#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    ["Hello World".into(), 1i32.into(), 1i32.into(), 1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"UPDATE "post" SET "title" = $1, "version" = "version" + $2"#,
+                        r#"WHERE "post"."id" = $3 AND "post"."version" = $4"#,
+                        r#"RETURNING "id", "title", "version""#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    ["Hello World".into(), 1i32.into(), 1i32.into(), 1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "post"."id", "post"."title", "post"."version" FROM "post" WHERE "post"."id" = $1 LIMIT $2"#,
+                    [1i32.into(), 1u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "post" WHERE "post"."id" = $1 AND "post"."version" = $2"#,
+                    [1i32.into(), 1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "post" SET "title" = $1, "version" = "version" + $2"#,
+                    ["Hi".into(), 1i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn update_with_version_not_found_or_noop() -> Result<(), DbErr> {
+        let model = versioned::Model {
+            id: 1,
+            title: "Hello".to_owned(),
+            version: 1,
+        };
+        let stale = versioned::Model {
+            version: 2,
+            ..model.clone()
+        };
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .append_query_results([vec![], vec![stale], vec![model.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            versioned::ActiveModel {
+                title: Set("Hello World".to_owned()),
+                ..model.clone().into_active_model()
+            }
+            .update(&db)
+            .await,
+            Err(DbErr::RecordNotUpdated)
+        );
+
+        assert_eq!(
+            model.clone().into_active_model().update(&db).await,
+            Err(DbErr::RecordVersionMismatch)
+        );
+
+        assert_eq!(model.clone().into_active_model().update(&db).await?, model);
+
+        let select = Transaction::from_sql_and_values(
+            DbBackend::MySql,
+            r#"SELECT `post`.`id`, `post`.`title`, `post`.`version` FROM `post` WHERE `post`.`id` = ? LIMIT ?"#,
+            [1i32.into(), 1u64.into()],
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    [
+                        r#"UPDATE `post` SET `title` = ?, `version` = `version` + ?"#,
+                        r#"WHERE `post`.`id` = ? AND `post`.`version` = ?"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    ["Hello World".into(), 1i32.into(), 1i32.into(), 1i32.into()]
+                ),
+                select.clone(),
+                select.clone(),
+                select,
+            ]
+        );
+
+        Ok(())
+    }
+
+    mod composite {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "hello")]
+        pub struct Model {
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub tenant_id: i32,
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub region: String,
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub partition: i16,
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub id: i64,
+            pub name: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[smol_potat::test]
+    async fn update_composite_key_4() -> Result<(), DbErr> {
+        let model = composite::Model {
+            tenant_id: 1,
+            region: "eu".to_owned(),
+            partition: 2,
+            id: 3,
+            name: "Hello".to_owned(),
+        };
+        let updated = composite::Model {
+            name: "Hello World".to_owned(),
+            ..model.clone()
+        };
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_query_results([vec![updated.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            composite::ActiveModel {
+                name: Set("Hello World".to_owned()),
+                ..model.into_active_model()
+            }
+            .update(&db)
+            .await?,
+            updated
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    [
+                        r#"UPDATE `hello` SET `name` = ?"#,
+                        r#"WHERE `hello`.`tenant_id` = ? AND `hello`.`region` = ? AND `hello`.`partition` = ? AND `hello`.`id` = ?"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [
+                        "Hello World".into(),
+                        1i32.into(),
+                        "eu".into(),
+                        2i16.into(),
+                        3i64.into()
+                    ]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    [
+                        r#"SELECT `hello`.`tenant_id`, `hello`.`region`, `hello`.`partition`, `hello`.`id`, `hello`.`name`"#,
+                        r#"FROM `hello`"#,
+                        r#"WHERE `hello`.`tenant_id` = ? AND `hello`.`region` = ? AND `hello`.`partition` = ? AND `hello`.`id` = ?"#,
+                        r#"LIMIT ?"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [
+                        1i32.into(),
+                        "eu".into(),
+                        2i16.into(),
+                        3i64.into(),
+                        1u64.into()
+                    ]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn update_many_with_returning() -> Result<(), DbErr> {
+        let updated_cake = cake::Model {
+            id: 1,
+            name: "Cheese Cake".to_owned(),
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([vec![updated_cake.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            Update::many(cake::Entity)
+                .col_expr(cake::Column::Name, Expr::value("Cheese Cake"))
+                .filter(cake::Column::Id.eq(1))
+                .exec_with_returning(&db)
+                .await?,
+            [updated_cake]
+        );
+
+        assert_eq!(
+            Update::many(cake::Entity)
+                .filter(cake::Column::Id.eq(1))
+                .exec_with_returning(&db)
+                .await?,
+            []
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"UPDATE "cake" SET "name" = $1 WHERE "cake"."id" = $2 RETURNING "id", "name""#,
+                ["Cheese Cake".into(), 1i32.into()]
+            )]
+        );
+
+        let db = MockDatabase::new(DbBackend::Sqlite).into_connection();
+
+        assert_eq!(
+            Update::many(cake::Entity)
+                .col_expr(cake::Column::Name, Expr::value("Cheese Cake"))
+                .exec_with_returning(&db)
+                .await,
+            Err(DbErr::BackendNotSupported {
+                db: "SQLite",
+                ctx: "UPDATE RETURNING",
+            })
+        );
+
+        Ok(())
+    }
 }
@@ -1,3 +1,4 @@
+use crate::{DbBackend, DbErr, ExecResult, QueryResult, QueryStream, TransactionStream};
 use std::{sync::Arc, time::Duration};
 
 pub(crate) type Callback = Arc<dyn Fn(&Info<'_>) + Send + Sync>;
@@ -14,19 +15,36 @@ pub struct Info<'a> {
     pub statement: &'a crate::Statement,
     /// Query execution failed
     pub failed: bool,
+    /// The database backend the query ran on
+    pub backend: DbBackend,
+    /// The number of rows affected by a statement run with `execute` or `execute_unprepared`
+    pub rows_affected: Option<u64>,
+    /// The number of rows returned by a query, or fetched from a stream before it was dropped
+    pub rows_returned: Option<u64>,
+    /// How long it took to acquire a connection from the pool, for a query run outside of a transaction
+    pub pool_wait: Option<Duration>,
+    /// Whether the query ran inside a transaction. The `BEGIN`, `COMMIT` and `ROLLBACK`
+    /// of a transaction are reported as such.
+    pub in_transaction: bool,
 }
 
 mod inner {
     #[allow(unused_macros)]
     macro_rules! metric {
-        ($metric_callback:expr, $stmt:expr, $code:block) => {{
+        ($metric_callback:expr, $stmt:expr, $pool_wait:expr, $in_transaction:expr, $code:block) => {{
             let _start = $metric_callback.is_some().then(std::time::SystemTime::now);
             let res = $code;
             if let (Some(_start), Some(callback)) = (_start, $metric_callback.as_deref()) {
+                let statement = $stmt;
                 let info = crate::metric::Info {
                     elapsed: _start.elapsed().unwrap_or_default(),
-                    statement: $stmt,
+                    statement,
                     failed: res.is_err(),
+                    backend: statement.db_backend,
+                    rows_affected: crate::metric::rows_affected(&res),
+                    rows_returned: crate::metric::rows_returned(&res),
+                    pool_wait: $pool_wait,
+                    in_transaction: $in_transaction,
                 };
                 callback(&info);
             }
@@ -35,3 +53,163 @@ mod inner {
     }
     pub(crate) use metric;
 }
+
+/// The result of a query, which may tell how many rows it affected or returned
+pub(crate) trait RowCount {
+    fn rows_affected(&self) -> Option<u64> {
+        None
+    }
+
+    fn rows_returned(&self) -> Option<u64> {
+        None
+    }
+
+    /// Hand the span of the statement over, for a stream to record its rows on once it ends
+    #[cfg(feature = "tracing-spans")]
+    fn trace(&mut self, _span: &tracing::Span) {}
+}
+
+impl RowCount for () {}
+
+impl RowCount for ExecResult {
+    fn rows_affected(&self) -> Option<u64> {
+        Some(ExecResult::rows_affected(self))
+    }
+}
+
+impl RowCount for Option<QueryResult> {
+    fn rows_returned(&self) -> Option<u64> {
+        Some(u64::from(self.is_some()))
+    }
+}
+
+impl RowCount for Vec<QueryResult> {
+    fn rows_returned(&self) -> Option<u64> {
+        Some(self.len() as u64)
+    }
+}
+
+// the rows of a stream are counted as they're fetched
+impl RowCount for QueryStream {
+    #[cfg(feature = "tracing-spans")]
+    fn trace(&mut self, span: &tracing::Span) {
+        self.trace_rows(span.clone())
+    }
+}
+
+impl RowCount for TransactionStream<'_> {
+    #[cfg(feature = "tracing-spans")]
+    fn trace(&mut self, span: &tracing::Span) {
+        self.trace_rows(span.clone())
+    }
+}
+
+pub(crate) fn rows_affected<T>(res: &Result<T, DbErr>) -> Option<u64>
+where
+    T: RowCount,
+{
+    res.as_ref().ok().and_then(RowCount::rows_affected)
+}
+
+pub(crate) fn rows_returned<T>(res: &Result<T, DbErr>) -> Option<u64>
+where
+    T: RowCount,
+{
+    res.as_ref().ok().and_then(RowCount::rows_returned)
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, error::*, tests_cfg::*, ConnectionTrait, DbBackend, MockDatabase,
+        MockExecResult, TransactionTrait,
+    };
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+    use std::sync::{Arc, Mutex};
+
+    #[smol_potat::test]
+    async fn metric_info() -> Result<(), DbErr> {
+        let cakes = [
+            cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "Chocolate Cake".to_owned(),
+            },
+        ];
+        let mut db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([cakes.clone(), cakes])
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+            ])
+            .into_connection();
+        let infos = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&infos);
+        db.set_metric_callback(move |info| {
+            assert_eq!(info.backend, DbBackend::Postgres);
+            assert!(info.pool_wait.is_none());
+            log.lock().expect("Fail to acquire metric log").push((
+                info.statement.sql.clone(),
+                info.rows_affected,
+                info.rows_returned,
+                info.in_transaction,
+                info.failed,
+            ));
+        });
+
+        cake::Entity::find().all(&db).await?;
+        db.transaction::<_, _, DbErr>(|txn| {
+            Box::pin(async move { cake::Entity::delete_by_id(1).exec(txn).await })
+        })
+        .await
+        .expect("Fail to delete cake");
+        db.execute_unprepared("VACUUM").await?;
+        {
+            let mut stream = cake::Entity::find().stream(&db).await?;
+            stream.try_next().await?;
+        }
+
+        assert_eq!(
+            *infos.lock().expect("Fail to acquire metric log"),
+            [
+                (
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#.to_owned(),
+                    None,
+                    Some(2),
+                    false,
+                    false
+                ),
+                ("BEGIN".to_owned(), None, None, true, false),
+                (
+                    r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#.to_owned(),
+                    Some(1),
+                    None,
+                    true,
+                    false
+                ),
+                ("COMMIT".to_owned(), None, None, true, false),
+                ("VACUUM".to_owned(), Some(0), None, false, false),
+                (
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#.to_owned(),
+                    None,
+                    Some(1),
+                    false,
+                    false
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
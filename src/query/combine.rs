@@ -1,9 +1,13 @@
 use crate::{
-    ColumnTrait, EntityTrait, IdenStatic, Iterable, QueryTrait, Select, SelectTwo, SelectTwoMany,
+    ColumnTrait, EntityTrait, IdenStatic, Iterable, QueryTrait, Select, SelectFour, SelectThree,
+    SelectTwo, SelectTwoMany,
 };
 use core::marker::PhantomData;
 pub use sea_query::JoinType;
-use sea_query::{Alias, ColumnRef, Iden, Order, SeaRc, SelectExpr, SelectStatement, SimpleExpr};
+use sea_query::{
+    Alias, ColumnRef, DynIden, Expr, Iden, IntoIden, Order, SeaRc, SelectExpr, SelectStatement,
+    SimpleExpr,
+};
 
 macro_rules! select_def {
     ( $ident: ident, $str: expr ) => {
@@ -27,6 +31,8 @@ macro_rules! select_def {
 
 select_def!(SelectA, "A_");
 select_def!(SelectB, "B_");
+select_def!(SelectC, "C_");
+select_def!(SelectD, "D_");
 
 impl<E> Select<E>
 where
@@ -81,6 +87,19 @@ where
         SelectTwo::new(self.into_query())
     }
 
+    /// Selects an Entity from the table joined under the given alias, see [QuerySelect::join_as](crate::QuerySelect::join_as),
+    /// and returns it together with the Entity from `Self`
+    pub fn select_also_as<F, I>(mut self, _: F, alias: I) -> SelectTwo<E, F>
+    where
+        F: EntityTrait,
+        I: IntoIden,
+    {
+        self = self.apply_alias(SelectA.as_str());
+        let mut select_two = SelectTwo::new_without_prepare(self.into_query());
+        prepare_select_col::<F, _, _>(&mut select_two, SelectB, Some(alias.into_iden()));
+        select_two
+    }
+
     /// Makes a SELECT operation in conjunction to another relation
     pub fn select_with<F>(mut self, _: F) -> SelectTwoMany<E, F>
     where
@@ -108,9 +127,82 @@ where
     }
 
     fn prepare_select(mut self) -> Self {
-        prepare_select_two::<F, Self>(&mut self);
+        prepare_select_col::<F, _, _>(&mut self, SelectB, None);
         self
     }
+
+    /// Selects a third Entity and returns it together with the Entities from `Self`
+    pub fn select_also<G>(self, _: G) -> SelectThree<E, F, G>
+    where
+        G: EntityTrait,
+    {
+        let mut select_three = SelectThree::new(self.into_query());
+        prepare_select_col::<G, _, _>(&mut select_three, SelectC, None);
+        select_three
+    }
+
+    /// Selects a third Entity from the table joined under the given alias, see [QuerySelect::join_as](crate::QuerySelect::join_as),
+    /// and returns it together with the Entities from `Self`
+    pub fn select_also_as<G, I>(self, _: G, alias: I) -> SelectThree<E, F, G>
+    where
+        G: EntityTrait,
+        I: IntoIden,
+    {
+        let mut select_three = SelectThree::new(self.into_query());
+        prepare_select_col::<G, _, _>(&mut select_three, SelectC, Some(alias.into_iden()));
+        select_three
+    }
+}
+
+impl<E, F, G> SelectThree<E, F, G>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+    G: EntityTrait,
+{
+    pub(crate) fn new(query: SelectStatement) -> Self {
+        Self {
+            query,
+            entity: PhantomData,
+        }
+    }
+
+    /// Selects a fourth Entity and returns it together with the Entities from `Self`
+    pub fn select_also<H>(self, _: H) -> SelectFour<E, F, G, H>
+    where
+        H: EntityTrait,
+    {
+        let mut select_four = SelectFour::new(self.into_query());
+        prepare_select_col::<H, _, _>(&mut select_four, SelectD, None);
+        select_four
+    }
+
+    /// Selects a fourth Entity from the table joined under the given alias, see [QuerySelect::join_as](crate::QuerySelect::join_as),
+    /// and returns it together with the Entities from `Self`
+    pub fn select_also_as<H, I>(self, _: H, alias: I) -> SelectFour<E, F, G, H>
+    where
+        H: EntityTrait,
+        I: IntoIden,
+    {
+        let mut select_four = SelectFour::new(self.into_query());
+        prepare_select_col::<H, _, _>(&mut select_four, SelectD, Some(alias.into_iden()));
+        select_four
+    }
+}
+
+impl<E, F, G, H> SelectFour<E, F, G, H>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+    G: EntityTrait,
+    H: EntityTrait,
+{
+    pub(crate) fn new(query: SelectStatement) -> Self {
+        Self {
+            query,
+            entity: PhantomData,
+        }
+    }
 }
 
 impl<E, F> SelectTwoMany<E, F>
@@ -128,7 +220,7 @@ where
     }
 
     fn prepare_select(mut self) -> Self {
-        prepare_select_two::<F, Self>(&mut self);
+        prepare_select_col::<F, _, _>(&mut self, SelectB, None);
         self
     }
 
@@ -140,15 +232,21 @@ where
     }
 }
 
-fn prepare_select_two<F, S>(selector: &mut S)
+/// Select the columns of `F` with the given prefix, from its own table or from the given table alias
+fn prepare_select_col<F, S, A>(selector: &mut S, prefix: A, table: Option<DynIden>)
 where
     F: EntityTrait,
     S: QueryTrait<QueryStatement = SelectStatement>,
+    A: IdenStatic,
 {
     for col in <F::Column as Iterable>::iter() {
-        let alias = format!("{}{}", SelectB.as_str(), col.as_str());
+        let alias = format!("{}{}", prefix.as_str(), col.as_str());
+        let expr = match &table {
+            Some(table) => Expr::col((SeaRc::clone(table), col.into_iden())),
+            None => col.into_expr(),
+        };
         selector.query().expr(SelectExpr {
-            expr: col.select_as(col.into_expr()),
+            expr: col.select_as(expr),
             alias: Some(SeaRc::new(Alias::new(&alias))),
             window: None,
         });
@@ -157,8 +255,11 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::tests_cfg::{cake, fruit};
-    use crate::{ColumnTrait, DbBackend, EntityTrait, QueryFilter, QuerySelect, QueryTrait};
+    use crate::tests_cfg::{cake, cake_filling, filling, fruit};
+    use crate::{
+        ColumnTrait, DbBackend, EntityTrait, QueryFilter, QuerySelect, QueryTrait, RelationTrait,
+    };
+    use sea_query::{Alias, Expr, JoinType};
 
     #[test]
     fn alias_1() {
@@ -243,4 +344,51 @@ mod tests {
             ].join(" ")
         );
     }
+
+    #[test]
+    fn select_also_3() {
+        assert_eq!(
+            cake::Entity::find()
+                .left_join(fruit::Entity)
+                .select_also(fruit::Entity)
+                .join(JoinType::LeftJoin, cake_filling::Relation::Cake.def().rev())
+                .join(JoinType::LeftJoin, cake_filling::Relation::Filling.def())
+                .select_also(filling::Entity)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                "`fruit`.`id` AS `B_id`, `fruit`.`name` AS `B_name`, `fruit`.`cake_id` AS `B_cake_id`,",
+                "`filling`.`id` AS `C_id`, `filling`.`name` AS `C_name`, `filling`.`vendor_id` AS `C_vendor_id`",
+                "FROM `cake` LEFT JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id`",
+                "LEFT JOIN `cake_filling` ON `cake`.`id` = `cake_filling`.`cake_id`",
+                "LEFT JOIN `filling` ON `cake_filling`.`filling_id` = `filling`.`id`",
+            ].join(" ")
+        );
+    }
+
+    #[test]
+    fn select_also_as_1() {
+        assert_eq!(
+            fruit::Entity::find()
+                .join(JoinType::LeftJoin, fruit::Relation::Cake.def())
+                .select_also(cake::Entity)
+                .join_as(JoinType::LeftJoin, cake::Relation::Fruit.def(), Alias::new("sibling"))
+                .filter(
+                    Expr::col((Alias::new("sibling"), fruit::Column::Id))
+                        .ne(Expr::col((fruit::Entity, fruit::Column::Id)))
+                )
+                .select_also_as(fruit::Entity, Alias::new("sibling"))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `fruit`.`id` AS `A_id`, `fruit`.`name` AS `A_name`, `fruit`.`cake_id` AS `A_cake_id`,",
+                "`cake`.`id` AS `B_id`, `cake`.`name` AS `B_name`,",
+                "`sibling`.`id` AS `C_id`, `sibling`.`name` AS `C_name`, `sibling`.`cake_id` AS `C_cake_id`",
+                "FROM `fruit` LEFT JOIN `cake` ON `fruit`.`cake_id` = `cake`.`id`",
+                "LEFT JOIN `fruit` AS `sibling` ON `cake`.`id` = `sibling`.`cake_id`",
+                "WHERE `sibling`.`id` <> `fruit`.`id`",
+            ].join(" ")
+        );
+    }
 }
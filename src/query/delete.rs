@@ -1,9 +1,13 @@
+use super::{
+    soft_delete::{soft_delete_column, soft_delete_stmt, SoftDelete},
+    update::version_of,
+};
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyToColumn, QueryFilter, QueryTrait,
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbBackend, DbErr, EntityTrait, IntoActiveModel,
+    Iterable, PrimaryKeyToColumn, QueryFilter, QueryTrait, Statement,
 };
 use core::marker::PhantomData;
-use sea_query::DeleteStatement;
+use sea_query::{DeleteStatement, IntoCondition, UpdateStatement};
 
 /// Defines the structure for a delete operation
 #[derive(Clone, Debug)]
@@ -17,6 +21,7 @@ where
 {
     pub(crate) query: DeleteStatement,
     pub(crate) model: A,
+    pub(crate) soft_delete: Option<SoftDelete>,
 }
 
 /// Perform a delete operation on multiple models
@@ -27,6 +32,7 @@ where
 {
     pub(crate) query: DeleteStatement,
     pub(crate) entity: PhantomData<E>,
+    pub(crate) soft_delete: Option<SoftDelete>,
 }
 
 impl Delete {
@@ -71,6 +77,7 @@ impl Delete {
                 .from_table(A::Entity::default().table_ref())
                 .to_owned(),
             model: model.into_active_model(),
+            soft_delete: soft_delete_column::<E>().map(|_| SoftDelete::default()),
         };
         myself.prepare()
     }
@@ -97,6 +104,7 @@ impl Delete {
                 .from_table(entity.table_ref())
                 .to_owned(),
             entity: PhantomData,
+            soft_delete: soft_delete_column::<E>().map(|_| SoftDelete::default()),
         }
     }
 }
@@ -116,8 +124,38 @@ where
                 ActiveValue::NotSet => panic!("PrimaryKey is not set"),
             }
         }
+        if let Some((col, value)) = version_of(&self.model) {
+            self = self.filter(col.eq(value));
+        }
+        self
+    }
+
+    /// Delete the row for real, even if the Entity has a soft delete column
+    pub fn force_delete(mut self) -> Self {
+        self.soft_delete = None;
+        self
+    }
+
+    /// The UPDATE statement to execute in place of the DELETE if the row is to be soft deleted
+    pub(crate) fn soft_delete_query(&self) -> Result<Option<UpdateStatement>, DbErr> {
+        soft_delete_stmt::<A::Entity>(self.soft_delete.as_ref())
+    }
+}
+
+impl<E> DeleteMany<E>
+where
+    E: EntityTrait,
+{
+    /// Delete the rows for real, even if the Entity has a soft delete column
+    pub fn force_delete(mut self) -> Self {
+        self.soft_delete = None;
         self
     }
+
+    /// The UPDATE statement to execute in place of the DELETE if the rows are to be soft deleted
+    pub(crate) fn soft_delete_query(&self) -> Result<Option<UpdateStatement>, DbErr> {
+        soft_delete_stmt::<E>(self.soft_delete.as_ref())
+    }
 }
 
 impl<A> QueryFilter for DeleteOne<A>
@@ -127,8 +165,23 @@ where
     type QueryStatement = DeleteStatement;
 
     fn query(&mut self) -> &mut DeleteStatement {
+        if let Some(soft_delete) = self.soft_delete.as_mut() {
+            soft_delete.query_changed();
+        }
         &mut self.query
     }
+    // the conditions are also collected for the soft delete UPDATE statement
+    fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        let condition = filter.into_condition();
+        if let Some(soft_delete) = self.soft_delete.as_mut() {
+            soft_delete.add_condition(condition.clone());
+        }
+        self.query.cond_where(condition);
+        self
+    }
 }
 
 impl<E> QueryFilter for DeleteMany<E>
@@ -138,8 +191,23 @@ where
     type QueryStatement = DeleteStatement;
 
     fn query(&mut self) -> &mut DeleteStatement {
+        if let Some(soft_delete) = self.soft_delete.as_mut() {
+            soft_delete.query_changed();
+        }
         &mut self.query
     }
+    // the conditions are also collected for the soft delete UPDATE statement
+    fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        let condition = filter.into_condition();
+        if let Some(soft_delete) = self.soft_delete.as_mut() {
+            soft_delete.add_condition(condition.clone());
+        }
+        self.query.cond_where(condition);
+        self
+    }
 }
 
 impl<A> QueryTrait for DeleteOne<A>
@@ -149,6 +217,9 @@ where
     type QueryStatement = DeleteStatement;
 
     fn query(&mut self) -> &mut DeleteStatement {
+        if let Some(soft_delete) = self.soft_delete.as_mut() {
+            soft_delete.query_changed();
+        }
         &mut self.query
     }
 
@@ -159,6 +230,15 @@ where
     fn into_query(self) -> DeleteStatement {
         self.query
     }
+
+    /// Build the statement run by `exec`, i.e. the UPDATE statement marking the rows
+    /// as deleted if they are to be soft deleted
+    fn build(&self, db_backend: DbBackend) -> Statement {
+        match self.soft_delete_query() {
+            Ok(Some(update)) => db_backend.build(&update),
+            _ => db_backend.build(&self.query),
+        }
+    }
 }
 
 impl<E> QueryTrait for DeleteMany<E>
@@ -168,6 +248,9 @@ where
     type QueryStatement = DeleteStatement;
 
     fn query(&mut self) -> &mut DeleteStatement {
+        if let Some(soft_delete) = self.soft_delete.as_mut() {
+            soft_delete.query_changed();
+        }
         &mut self.query
     }
 
@@ -178,6 +261,15 @@ where
     fn into_query(self) -> DeleteStatement {
         self.query
     }
+
+    /// Build the statement run by `exec`, i.e. the UPDATE statement marking the rows
+    /// as deleted if they are to be soft deleted
+    fn build(&self, db_backend: DbBackend) -> Statement {
+        match self.soft_delete_query() {
+            Ok(Some(update)) => db_backend.build(&update),
+            _ => db_backend.build(&self.query),
+        }
+    }
 }
 
 #[cfg(test)]
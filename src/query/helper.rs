@@ -709,23 +709,19 @@ pub(crate) fn join_tbl_on_condition(
     owner_keys: Identity,
     foreign_keys: Identity,
 ) -> SimpleExpr {
-    match (owner_keys, foreign_keys) {
-        (Identity::Unary(o1), Identity::Unary(f1)) => {
-            Expr::col((SeaRc::clone(&from_tbl), o1)).equals((SeaRc::clone(&to_tbl), f1))
-        }
-        (Identity::Binary(o1, o2), Identity::Binary(f1, f2)) => {
-            Expr::col((SeaRc::clone(&from_tbl), o1))
-                .equals((SeaRc::clone(&to_tbl), f1))
-                .and(Expr::col((SeaRc::clone(&from_tbl), o2)).equals((SeaRc::clone(&to_tbl), f2)))
-        }
-        (Identity::Ternary(o1, o2, o3), Identity::Ternary(f1, f2, f3)) => {
-            Expr::col((SeaRc::clone(&from_tbl), o1))
-                .equals((SeaRc::clone(&to_tbl), f1))
-                .and(Expr::col((SeaRc::clone(&from_tbl), o2)).equals((SeaRc::clone(&to_tbl), f2)))
-                .and(Expr::col((SeaRc::clone(&from_tbl), o3)).equals((SeaRc::clone(&to_tbl), f3)))
-        }
-        _ => panic!("Owner key and foreign key mismatch"),
+    if owner_keys.arity() != foreign_keys.arity() {
+        panic!("Owner key and foreign key mismatch");
+    }
+    let mut cond: Option<SimpleExpr> = None;
+    for (owner_key, foreign_key) in owner_keys.into_iter().zip(foreign_keys) {
+        let expr = Expr::col((SeaRc::clone(&from_tbl), owner_key))
+            .equals((SeaRc::clone(&to_tbl), foreign_key));
+        cond = Some(match cond {
+            Some(cond) => cond.and(expr),
+            None => expr,
+        });
     }
+    cond.expect("Identity should contain at least one column")
 }
 
 pub(crate) fn unpack_table_ref(table_ref: &TableRef) -> DynIden {
@@ -1,9 +1,9 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityName, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyTrait, QueryTrait,
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbBackend, EntityName, EntityTrait, IdenStatic,
+    IntoActiveModel, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, QueryTrait,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, InsertStatement, OnConflict, ValueTuple};
+use sea_query::{Expr, InsertStatement, OnConflict, Query, Value, ValueTuple};
 
 /// Performs INSERT operations on a ActiveModel
 #[derive(Debug)]
@@ -14,6 +14,9 @@ where
     pub(crate) query: InsertStatement,
     pub(crate) columns: Vec<bool>,
     pub(crate) primary_key: Option<ValueTuple>,
+    pub(crate) primary_keys: Vec<Option<ValueTuple>>,
+    pub(crate) upsert_on: Option<Vec<<A::Entity as EntityTrait>::Column>>,
+    pub(crate) max_bind_params: Option<usize>,
     pub(crate) model: PhantomData<A>,
 }
 
@@ -38,6 +41,9 @@ where
                 .to_owned(),
             columns: Vec::new(),
             primary_key: None,
+            primary_keys: Vec::new(),
+            upsert_on: None,
+            max_bind_params: None,
             model: PhantomData,
         }
     }
@@ -119,6 +125,7 @@ where
         M: IntoActiveModel<A>,
     {
         let mut am: A = m.into_active_model();
+        self.primary_keys.push(am.get_primary_key_value());
         self.primary_key =
             if !<<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment() {
                 am.get_primary_key_value()
@@ -129,7 +136,12 @@ where
         let mut values = Vec::new();
         let columns_empty = self.columns.is_empty();
         for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
-            let av = am.take(col);
+            let mut av = am.take(col);
+            if av.is_not_set() {
+                if let Some(value) = col.auto_timestamp() {
+                    av = ActiveValue::Set(value);
+                }
+            }
             let av_has_val = av.is_set() || av.is_unchanged();
             if columns_empty {
                 self.columns.push(av_has_val);
@@ -208,6 +220,224 @@ where
         self.query.on_conflict(on_conflict);
         self
     }
+
+    /// Update the existing row instead when one with the same primary key exists.
+    /// All inserted columns other than the primary key and `#[sea_orm(created_at)]` columns are updated.
+    ///
+    /// This is `ON CONFLICT (..) DO UPDATE` on Postgres and SQLite, and `ON DUPLICATE KEY UPDATE` on MySQL.
+    /// Call it after all models have been added.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let orange = cake::Model {
+    ///     id: 2,
+    ///     name: "Orange".to_owned(),
+    /// };
+    /// assert_eq!(
+    ///     Insert::one(orange.clone())
+    ///         .upsert()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name""#,
+    /// );
+    /// assert_eq!(
+    ///     Insert::one(orange)
+    ///         .upsert()
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r#"INSERT INTO `cake` (`id`, `name`) VALUES (2, 'Orange') ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)"#,
+    /// );
+    /// ```
+    pub fn upsert(self) -> Self {
+        self.upsert_on(<A::Entity as EntityTrait>::PrimaryKey::iter().map(|key| key.into_column()))
+    }
+
+    /// Like [Insert::upsert], but with the conflict target being the columns of a unique index
+    /// instead, e.g. a `#[sea_orm(unique)]` column. They are left out of the update as well.
+    ///
+    /// MySQL can't name the conflict target, the row is updated on conflict of any unique index.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Insert::one(cake::ActiveModel {
+    ///         name: Set("Orange".to_owned()),
+    ///         ..Default::default()
+    ///     })
+    ///     .upsert_on([cake::Column::Name])
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"INSERT INTO "cake" ("name") VALUES ('Orange') ON CONFLICT ("name") DO UPDATE SET "name" = "excluded"."name""#,
+    /// );
+    /// ```
+    pub fn upsert_on<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = <A::Entity as EntityTrait>::Column>,
+    {
+        let target: Vec<_> = columns.into_iter().collect();
+        let keys: Vec<_> = target
+            .iter()
+            .copied()
+            .chain(<A::Entity as EntityTrait>::PrimaryKey::iter().map(|key| key.into_column()))
+            .collect();
+        let is_key = |col: &<A::Entity as EntityTrait>::Column| {
+            keys.iter().any(|key| key.as_str() == col.as_str())
+        };
+        let mut update: Vec<_> = <A::Entity as EntityTrait>::Column::iter()
+            .zip(self.columns.iter())
+            .filter(|(col, has_val)| **has_val && !is_key(col) && !col.def().is_created_at())
+            .map(|(col, _)| col)
+            .collect();
+        if update.is_empty() {
+            // nothing else to update, but a no-op update still lets the row be returned
+            update.extend(target.first().copied());
+        }
+        self = self.on_conflict(
+            OnConflict::columns(target.iter().copied())
+                .update_columns(update)
+                .to_owned(),
+        );
+        self.upsert_on = Some(target);
+        self
+    }
+
+    /// Set the maximum number of bind parameters of each statement of
+    /// [Insert::exec_chunked](crate::Insert::exec_chunked), instead of
+    /// [DbBackend::max_bind_params]. SQLite before 3.32 only allows 999, for example.
+    pub fn max_bind_params(mut self, max_bind_params: usize) -> Self {
+        self.max_bind_params = Some(max_bind_params);
+        self
+    }
+
+    /// The inserted columns
+    fn inserted_columns(&self) -> Vec<<A::Entity as EntityTrait>::Column> {
+        <A::Entity as EntityTrait>::Column::iter()
+            .zip(self.columns.iter())
+            .filter_map(|(col, has_val)| has_val.then_some(col))
+            .collect()
+    }
+
+    /// The values of each inserted row, split from the built statement in which every inserted
+    /// column binds one value per row. `None` if the statement doesn't line up with the rows.
+    fn row_values(&self, db_backend: DbBackend) -> Option<Vec<Vec<Value>>> {
+        let len = self.inserted_columns().len();
+        let (_, values) = self
+            .query
+            .build_any(db_backend.get_query_builder().as_ref());
+        let total = len * self.primary_keys.len();
+        if len == 0 || values.0.len() < total {
+            return None;
+        }
+        let mut values = values.0;
+        values.truncate(total);
+        Some(values.chunks(len).map(<[Value]>::to_vec).collect())
+    }
+
+    /// The columns identifying the inserted rows, and their values for each row if known up front:
+    /// the conflict target of an upsert if all its columns are inserted, otherwise the primary key
+    pub(crate) fn row_keys(
+        &self,
+        db_backend: DbBackend,
+    ) -> (
+        Vec<<A::Entity as EntityTrait>::Column>,
+        Vec<Option<ValueTuple>>,
+    ) {
+        let columns = self.inserted_columns();
+        if let Some(target) = &self.upsert_on {
+            let idx: Option<Vec<_>> = target
+                .iter()
+                .map(|key| columns.iter().position(|col| col.as_str() == key.as_str()))
+                .collect();
+            if let (Some(idx), Some(rows)) = (idx, self.row_values(db_backend)) {
+                let keys = rows
+                    .iter()
+                    .map(|row| into_value_tuple(idx.iter().map(|i| row[*i].clone()).collect()))
+                    .collect();
+                return (target.clone(), keys);
+            }
+        }
+        let primary_key = <A::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(|key| key.into_column())
+            .collect();
+        (primary_key, self.primary_keys.clone())
+    }
+
+    /// Split the rows into INSERT statements each binding no more than
+    /// [DbBackend::max_bind_params] values, or as set by [Insert::max_bind_params],
+    /// paired with the primary key of the last row of the statement if it's not generated
+    /// by the database. Everything but the rows is kept from the original statement,
+    /// and the values it binds, e.g. in `ON CONFLICT`, are counted in every chunk.
+    pub(crate) fn into_chunks(
+        self,
+        db_backend: DbBackend,
+    ) -> Vec<(Option<ValueTuple>, InsertStatement)> {
+        let auto_increment =
+            <<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment();
+        let columns = self.inserted_columns();
+        let rows = self.row_values(db_backend);
+        let mut template = self.query;
+        // replacing the values by a SELECT clears them, and the next values replace the SELECT;
+        // this fails if the columns of the statement have been changed
+        let select = Query::select()
+            .exprs(columns.iter().map(|_| Expr::cust("NULL")))
+            .to_owned();
+        let rows = match rows {
+            Some(rows) if template.select_from(select).is_ok() => rows,
+            _ => return vec![(self.primary_key, template)],
+        };
+        let max_bind_params = self
+            .max_bind_params
+            .unwrap_or_else(|| db_backend.max_bind_params());
+        let template_binds = db_backend
+            .build(&template)
+            .values
+            .map_or(0, |values| values.0.len());
+        let chunk_size = (max_bind_params.saturating_sub(template_binds) / columns.len()).max(1);
+        let mut primary_keys = self.primary_keys.into_iter();
+        let mut rows = rows.into_iter().peekable();
+        let mut chunks = Vec::new();
+        while rows.peek().is_some() {
+            let mut query = template.clone();
+            for row in rows.by_ref().take(chunk_size) {
+                query.values_panic(
+                    columns
+                        .iter()
+                        .zip(row)
+                        .map(|(col, value)| col.save_as(Expr::val(value))),
+                );
+            }
+            let primary_key = primary_keys
+                .by_ref()
+                .take(chunk_size)
+                .last()
+                .flatten()
+                .filter(|_| !auto_increment);
+            chunks.push((primary_key, query));
+        }
+        chunks
+    }
+}
+
+fn into_value_tuple(values: Vec<Value>) -> Option<ValueTuple> {
+    let tuple = match values.as_slice() {
+        [a] => ValueTuple::One(a.clone()),
+        [a, b] => ValueTuple::Two(a.clone(), b.clone()),
+        [a, b, c] => ValueTuple::Three(a.clone(), b.clone(), c.clone()),
+        [a, b, c, d] => ValueTuple::Four(a.clone(), b.clone(), c.clone(), d.clone()),
+        [a, b, c, d, e] => ValueTuple::Five(a.clone(), b.clone(), c.clone(), d.clone(), e.clone()),
+        [a, b, c, d, e, f] => ValueTuple::Six(
+            a.clone(),
+            b.clone(),
+            c.clone(),
+            d.clone(),
+            e.clone(),
+            f.clone(),
+        ),
+        _ => return None,
+    };
+    Some(tuple)
 }
 
 impl<A> QueryTrait for Insert<A>
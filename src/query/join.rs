@@ -1,6 +1,7 @@
 use crate::{
-    join_tbl_on_condition, unpack_table_ref, ColumnTrait, EntityTrait, IdenStatic, Iterable,
-    Linked, QuerySelect, Related, Select, SelectA, SelectB, SelectTwo, SelectTwoMany,
+    join_tbl_on_condition, query::soft_delete::join_not_deleted, unpack_table_ref, ColumnTrait,
+    EntityTrait, IdenStatic, Iterable, Linked, QuerySelect, Related, Select, SelectA, SelectB,
+    SelectTwo, SelectTwoMany,
 };
 pub use sea_query::JoinType;
 use sea_query::{Alias, Condition, Expr, IntoIden, SeaRc, SelectExpr};
@@ -50,7 +51,7 @@ where
         R: EntityTrait,
         E: Related<R>,
     {
-        self.left_join(r).select_also(r)
+        self.left_join_not_deleted::<R>().select_also(r)
     }
 
     /// Left Join with a Related Entity and select the related Entity as a `Vec`
@@ -59,7 +60,16 @@ where
         R: EntityTrait,
         E: Related<R>,
     {
-        self.left_join(r).select_with(r)
+        self.left_join_not_deleted::<R>().select_with(r)
+    }
+
+    /// Left Join with a Related Entity, leaving out its soft deleted rows
+    fn left_join_not_deleted<R>(self) -> Self
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        self.join_join(JoinType::LeftJoin, join_not_deleted::<R>(E::to()), E::via())
     }
 
     /// Left Join with a Linked Entity and select both Entity.
@@ -1,10 +1,14 @@
 use crate::{
-    error::*, Condition, ConnectionTrait, DbErr, EntityTrait, Identity, ModelTrait, QueryFilter,
-    Related, RelationType, Select,
+    error::*, join_linked, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, IdenStatic,
+    Identity, Iterable, LinkDef, Linked, ModelTrait, QueryFilter, QuerySelect, Related,
+    RelationType, Select, SelectA, SelectB, SelectTwo,
 };
 use async_trait::async_trait;
-use sea_query::{ColumnRef, DynIden, Expr, IntoColumnRef, SimpleExpr, TableRef, ValueTuple};
-use std::{collections::HashMap, str::FromStr};
+use sea_query::{
+    Alias, ColumnRef, DynIden, Expr, IntoColumnRef, IntoIden, SeaRc, SelectExpr, SimpleExpr,
+    TableRef, Value, ValueTuple,
+};
+use std::{collections::HashMap, marker::PhantomData, str::FromStr};
 
 /// Entity, or a Select<Entity>; to be used as parameters in [`LoaderTrait`]
 pub trait EntityOrSelect<E: EntityTrait>: Send {
@@ -18,7 +22,7 @@ pub trait LoaderTrait {
     /// Source model
     type Model: ModelTrait;
 
-    /// Used to eager load has_one relations
+    /// Used to eager load has_one relations, including the ones through an intermediate Entity
     async fn load_one<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Option<R::Model>>, DbErr>
     where
         C: ConnectionTrait,
@@ -27,7 +31,7 @@ pub trait LoaderTrait {
         S: EntityOrSelect<R>,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
 
-    /// Used to eager load has_many relations
+    /// Used to eager load has_many relations, including the ones through an intermediate Entity
     async fn load_many<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<R::Model>>, DbErr>
     where
         C: ConnectionTrait,
@@ -51,6 +55,30 @@ pub trait LoaderTrait {
         V: EntityTrait,
         V::Model: Send + Sync,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load the Entities at the other end of a [`Linked`] chain
+    async fn load_linked<L, S, C>(
+        &self,
+        linked: L,
+        stmt: S,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = <<Self as LoaderTrait>::Model as ModelTrait>::Entity> + Send,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync,
+        S: EntityOrSelect<L::ToEntity>;
+
+    /// Used to eager load a tree of relations, described by [`LoadOne`], [`LoadMany`],
+    /// [`LoadManyToMany`], [`LoadLinked`] and tuples of them, in one query per node
+    async fn load_nested<N, C>(
+        &self,
+        node: N,
+        db: &C,
+    ) -> Result<Vec<(Self::Model, N::Output)>, DbErr>
+    where
+        C: ConnectionTrait,
+        N: LoadNode<Self::Model>;
 }
 
 impl<E> EntityOrSelect<E> for E
@@ -117,6 +145,33 @@ where
     {
         self.as_slice().load_many_to_many(stmt, via, db).await
     }
+
+    async fn load_linked<L, S, C>(
+        &self,
+        linked: L,
+        stmt: S,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = <<Self as LoaderTrait>::Model as ModelTrait>::Entity> + Send,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync,
+        S: EntityOrSelect<L::ToEntity>,
+    {
+        self.as_slice().load_linked(linked, stmt, db).await
+    }
+
+    async fn load_nested<N, C>(
+        &self,
+        node: N,
+        db: &C,
+    ) -> Result<Vec<(Self::Model, N::Output)>, DbErr>
+    where
+        C: ConnectionTrait,
+        N: LoadNode<Self::Model>,
+    {
+        self.as_slice().load_nested(node, db).await
+    }
 }
 
 #[async_trait]
@@ -134,11 +189,15 @@ where
         S: EntityOrSelect<R>,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
     {
-        // we verify that is HasOne relation
-        if <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::via().is_some() {
-            return Err(query_err("Relation is ManytoMany instead of HasOne"));
-        }
         let rel_def = <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::to();
+        // a relation through an intermediate Entity is loaded like a Linked chain
+        if let Some(via) =
+            <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::via()
+        {
+            let data = load_along_link(self, vec![via, rel_def], stmt.select(), db).await?;
+            return Ok(data.into_iter().map(|vec| vec.into_iter().next()).collect());
+        }
+        // we verify that is HasOne relation
         if rel_def.rel_type == RelationType::HasMany {
             return Err(query_err("Relation is HasMany instead of HasOne"));
         }
@@ -184,12 +243,14 @@ where
         S: EntityOrSelect<R>,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
     {
-        // we verify that is HasMany relation
-
-        if <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::via().is_some() {
-            return Err(query_err("Relation is ManyToMany instead of HasMany"));
-        }
         let rel_def = <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::to();
+        // a relation through an intermediate Entity is loaded like a Linked chain
+        if let Some(via) =
+            <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::via()
+        {
+            return load_along_link(self, vec![via, rel_def], stmt.select(), db).await;
+        }
+        // we verify that is HasMany relation
         if rel_def.rel_type == RelationType::HasOne {
             return Err(query_err("Relation is HasOne instead of HasMany"));
         }
@@ -328,6 +389,480 @@ where
             return Err(query_err("Relation is not ManyToMany"));
         }
     }
+
+    async fn load_linked<L, S, C>(
+        &self,
+        linked: L,
+        stmt: S,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = <<Self as LoaderTrait>::Model as ModelTrait>::Entity> + Send,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync,
+        S: EntityOrSelect<L::ToEntity>,
+    {
+        load_along_link(self, linked.link(), stmt.select(), db).await
+    }
+
+    async fn load_nested<N, C>(
+        &self,
+        node: N,
+        db: &C,
+    ) -> Result<Vec<(Self::Model, N::Output)>, DbErr>
+    where
+        C: ConnectionTrait,
+        N: LoadNode<Self::Model>,
+    {
+        let outputs = node.load(self, db).await?;
+
+        Ok(self.iter().cloned().zip(outputs).collect())
+    }
+}
+
+/// A node in a tree of relations to be eager loaded by [`LoaderTrait::load_nested`]
+#[async_trait]
+pub trait LoadNode<M>: Send
+where
+    M: ModelTrait,
+{
+    /// The value loaded for each model
+    type Output: Send;
+
+    /// Load the values of this node for each of the given models, in the same order
+    async fn load<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+    where
+        C: ConnectionTrait;
+}
+
+/// Pair the related models with the relations nested beneath them;
+/// implemented by `()` when there is nothing nested and by each kind of [`LoadNode`]
+#[async_trait]
+pub trait LoadNested<M>: Send
+where
+    M: ModelTrait,
+{
+    /// The related model, along with its nested relations if any
+    type Nested: Send;
+
+    /// Load the nested relations for each of the given models, in the same order
+    async fn nest<C>(self, models: Vec<M>, db: &C) -> Result<Vec<Self::Nested>, DbErr>
+    where
+        C: ConnectionTrait;
+}
+
+#[async_trait]
+impl<M> LoadNested<M> for ()
+where
+    M: ModelTrait + Sync + 'static,
+{
+    type Nested = M;
+
+    async fn nest<C>(self, models: Vec<M>, _: &C) -> Result<Vec<Self::Nested>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(models)
+    }
+}
+
+macro_rules! impl_load_nested_for_node {
+    ( $ty:ty; $($T:ident),+ ) => {
+        #[async_trait]
+        impl<M, $($T),+> LoadNested<M> for $ty
+        where
+            M: ModelTrait + Sync + 'static,
+            Self: LoadNode<M>,
+        {
+            type Nested = (M, <Self as LoadNode<M>>::Output);
+
+            async fn nest<C>(self, models: Vec<M>, db: &C) -> Result<Vec<Self::Nested>, DbErr>
+            where
+                C: ConnectionTrait,
+            {
+                let outputs = self.load(&models, db).await?;
+
+                Ok(models.into_iter().zip(outputs).collect())
+            }
+        }
+    };
+}
+
+impl_load_nested_for_node!(LoadOne<R, S, N>; R, S, N);
+impl_load_nested_for_node!(LoadMany<R, S, N>; R, S, N);
+impl_load_nested_for_node!(LoadManyToMany<R, S, V, N>; R, S, V, N);
+impl_load_nested_for_node!(LoadLinked<L, S, N>; L, S, N);
+impl_load_nested_for_node!((T0, T1); T0, T1);
+impl_load_nested_for_node!((T0, T1, T2); T0, T1, T2);
+impl_load_nested_for_node!((T0, T1, T2, T3); T0, T1, T2, T3);
+
+/// Eager load a has_one relation with [`LoaderTrait::load_one`]
+#[derive(Debug)]
+pub struct LoadOne<R, S, N = ()> {
+    stmt: S,
+    nested: N,
+    entity: PhantomData<R>,
+}
+
+/// Eager load a has_many relation with [`LoaderTrait::load_many`]
+#[derive(Debug)]
+pub struct LoadMany<R, S, N = ()> {
+    stmt: S,
+    nested: N,
+    entity: PhantomData<R>,
+}
+
+/// Eager load a many_to_many relation with [`LoaderTrait::load_many_to_many`]
+#[derive(Debug)]
+pub struct LoadManyToMany<R, S, V, N = ()> {
+    stmt: S,
+    via: V,
+    nested: N,
+    entity: PhantomData<R>,
+}
+
+/// Eager load the Entities at the other end of a [`Linked`] chain with [`LoaderTrait::load_linked`]
+#[derive(Debug)]
+pub struct LoadLinked<L, S, N = ()> {
+    linked: L,
+    stmt: S,
+    nested: N,
+}
+
+impl<R, S> LoadOne<R, S>
+where
+    R: EntityTrait,
+    S: EntityOrSelect<R>,
+{
+    /// Load the related Entity, or the result of a Select<Entity>
+    pub fn new(stmt: S) -> Self {
+        Self {
+            stmt,
+            nested: (),
+            entity: PhantomData,
+        }
+    }
+
+    /// Also load the relations of the related models
+    pub fn with<N>(self, nested: N) -> LoadOne<R, S, N>
+    where
+        N: LoadNode<R::Model>,
+    {
+        LoadOne {
+            stmt: self.stmt,
+            nested,
+            entity: PhantomData,
+        }
+    }
+}
+
+impl<R, S> LoadMany<R, S>
+where
+    R: EntityTrait,
+    S: EntityOrSelect<R>,
+{
+    /// Load the related Entity, or the result of a Select<Entity>
+    pub fn new(stmt: S) -> Self {
+        Self {
+            stmt,
+            nested: (),
+            entity: PhantomData,
+        }
+    }
+
+    /// Also load the relations of the related models
+    pub fn with<N>(self, nested: N) -> LoadMany<R, S, N>
+    where
+        N: LoadNode<R::Model>,
+    {
+        LoadMany {
+            stmt: self.stmt,
+            nested,
+            entity: PhantomData,
+        }
+    }
+}
+
+impl<R, S, V> LoadManyToMany<R, S, V>
+where
+    R: EntityTrait,
+    S: EntityOrSelect<R>,
+    V: EntityTrait,
+{
+    /// Load the related Entity, or the result of a Select<Entity>, via the junction Entity
+    pub fn new(stmt: S, via: V) -> Self {
+        Self {
+            stmt,
+            via,
+            nested: (),
+            entity: PhantomData,
+        }
+    }
+
+    /// Also load the relations of the related models
+    pub fn with<N>(self, nested: N) -> LoadManyToMany<R, S, V, N>
+    where
+        N: LoadNode<R::Model>,
+    {
+        LoadManyToMany {
+            stmt: self.stmt,
+            via: self.via,
+            nested,
+            entity: PhantomData,
+        }
+    }
+}
+
+impl<L, S> LoadLinked<L, S>
+where
+    L: Linked,
+    S: EntityOrSelect<L::ToEntity>,
+{
+    /// Load the linked Entity, or the result of a Select<Entity>, along the given link
+    pub fn new(linked: L, stmt: S) -> Self {
+        Self {
+            linked,
+            stmt,
+            nested: (),
+        }
+    }
+
+    /// Also load the relations of the linked models
+    pub fn with<N>(self, nested: N) -> LoadLinked<L, S, N>
+    where
+        N: LoadNode<<L::ToEntity as EntityTrait>::Model>,
+    {
+        LoadLinked {
+            linked: self.linked,
+            stmt: self.stmt,
+            nested,
+        }
+    }
+}
+
+#[async_trait]
+impl<M, R, S, N> LoadNode<M> for LoadOne<R, S, N>
+where
+    M: ModelTrait + Sync,
+    M::Entity: Related<R>,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    S: EntityOrSelect<R>,
+    N: LoadNested<R::Model>,
+{
+    type Output = Option<N::Nested>;
+
+    async fn load<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let related = models.load_one(self.stmt, db).await?;
+        let found: Vec<bool> = related.iter().map(Option::is_some).collect();
+
+        let mut nested = self
+            .nested
+            .nest(related.into_iter().flatten().collect(), db)
+            .await?
+            .into_iter();
+
+        Ok(found
+            .into_iter()
+            .map(|found| if found { nested.next() } else { None })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<M, R, S, N> LoadNode<M> for LoadMany<R, S, N>
+where
+    M: ModelTrait + Sync,
+    M::Entity: Related<R>,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    S: EntityOrSelect<R>,
+    N: LoadNested<R::Model>,
+{
+    type Output = Vec<N::Nested>;
+
+    async fn load<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let related = models.load_many(self.stmt, db).await?;
+
+        regroup(related, self.nested, db).await
+    }
+}
+
+#[async_trait]
+impl<M, R, S, V, N> LoadNode<M> for LoadManyToMany<R, S, V, N>
+where
+    M: ModelTrait + Sync,
+    M::Entity: Related<R>,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    S: EntityOrSelect<R>,
+    V: EntityTrait,
+    V::Model: Send + Sync,
+    N: LoadNested<R::Model>,
+{
+    type Output = Vec<N::Nested>;
+
+    async fn load<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let related = models.load_many_to_many(self.stmt, self.via, db).await?;
+
+        regroup(related, self.nested, db).await
+    }
+}
+
+#[async_trait]
+impl<M, L, S, N> LoadNode<M> for LoadLinked<L, S, N>
+where
+    M: ModelTrait + Sync,
+    L: Linked<FromEntity = M::Entity> + Send,
+    <L::ToEntity as EntityTrait>::Model: Send + Sync,
+    S: EntityOrSelect<L::ToEntity>,
+    N: LoadNested<<L::ToEntity as EntityTrait>::Model>,
+{
+    type Output = Vec<N::Nested>;
+
+    async fn load<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let related = models.load_linked(self.linked, self.stmt, db).await?;
+
+        regroup(related, self.nested, db).await
+    }
+}
+
+macro_rules! impl_load_node_for_tuple {
+    ( $($T:ident : $N:tt),+ ) => {
+        #[async_trait]
+        impl<M, $($T),+> LoadNode<M> for ( $($T),+ )
+        where
+            M: ModelTrait + Sync,
+            $($T: LoadNode<M>),+
+        {
+            type Output = ( $($T::Output),+ );
+
+            #[allow(non_snake_case)]
+            async fn load<C>(self, models: &[M], db: &C) -> Result<Vec<Self::Output>, DbErr>
+            where
+                C: ConnectionTrait,
+            {
+                $(let mut $T = self.$N.load(models, db).await?.into_iter();)+
+
+                Ok((0..models.len())
+                    .map(|_| ( $($T.next().expect("Failed at aligning loaded values")),+ ))
+                    .collect())
+            }
+        }
+    };
+}
+
+impl_load_node_for_tuple!(T0:0, T1:1);
+impl_load_node_for_tuple!(T0:0, T1:1, T2:2);
+impl_load_node_for_tuple!(T0:0, T1:1, T2:2, T3:3);
+
+/// Load the Entities at the other end of a chain of relations, from the first one's `from_tbl`
+/// to the last one's `to_tbl`, for each of the given models
+async fn load_along_link<M, R, C>(
+    models: &[M],
+    link: Vec<LinkDef>,
+    stmt: Select<R>,
+    db: &C,
+) -> Result<Vec<Vec<R::Model>>, DbErr>
+where
+    M: ModelTrait + Sync,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    C: ConnectionTrait,
+{
+    let from_col = match link.first() {
+        Some(rel_def) => rel_def.from_col.clone(),
+        None => return Err(query_err("Linked does not have any relation")),
+    };
+    // the FromEntity is the last table joined, see `join_linked`
+    let from_tbl = Alias::new(&format!("r{}", link.len() - 1)).into_iden();
+
+    let keys: Vec<ValueTuple> = models
+        .iter()
+        .map(|model: &M| extract_key(&from_col, model))
+        .collect();
+
+    let condition = prepare_condition(&TableRef::Table(SeaRc::clone(&from_tbl)), &from_col, &keys);
+
+    let stmt = join_linked(stmt, link)
+        .filter(condition)
+        .apply_alias(SelectA.as_str());
+
+    // select the FromEntity alongside, so that each row can be traced back to its model
+    let mut stmt = SelectTwo::<R, M::Entity>::new_without_prepare(stmt.query);
+    for col in <<M::Entity as EntityTrait>::Column as Iterable>::iter() {
+        let alias = format!("{}{}", SelectB.as_str(), col.as_str());
+        let expr = Expr::col((SeaRc::clone(&from_tbl), col.into_iden()));
+        QuerySelect::query(&mut stmt).expr(SelectExpr {
+            expr: col.select_as(expr),
+            alias: Some(SeaRc::new(Alias::new(&alias))),
+            window: None,
+        });
+    }
+
+    let data = stmt.all(db).await?;
+
+    let mut hashmap: HashMap<String, Vec<R::Model>> = keys
+        .iter()
+        .map(|key: &ValueTuple| (format!("{key:?}"), Vec::new()))
+        .collect();
+
+    for (value, model) in data {
+        let model = model.ok_or_else(|| query_err("Failed at reading the linked model"))?;
+        let key = extract_key(&from_col, &model);
+
+        if let Some(vec) = hashmap.get_mut(&format!("{key:?}")) {
+            vec.push(value);
+        }
+    }
+
+    let result: Vec<Vec<R::Model>> = keys
+        .iter()
+        .map(|key: &ValueTuple| {
+            hashmap
+                .get(&format!("{key:?}"))
+                .cloned()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    Ok(result)
+}
+
+/// Load the nested relations of every related model in one go, then split them back per model
+async fn regroup<R, N, C>(
+    related: Vec<Vec<R>>,
+    nested: N,
+    db: &C,
+) -> Result<Vec<Vec<N::Nested>>, DbErr>
+where
+    R: ModelTrait + Sync,
+    N: LoadNested<R>,
+    C: ConnectionTrait,
+{
+    let lens: Vec<usize> = related.iter().map(Vec::len).collect();
+
+    let mut nested = nested
+        .nest(related.into_iter().flatten().collect(), db)
+        .await?
+        .into_iter();
+
+    Ok(lens
+        .into_iter()
+        .map(|len| nested.by_ref().take(len).collect())
+        .collect())
 }
 
 fn cmp_table_ref(left: &TableRef, right: &TableRef) -> bool {
@@ -339,50 +874,31 @@ fn extract_key<Model>(target_col: &Identity, model: &Model) -> ValueTuple
 where
     Model: ModelTrait,
 {
-    match target_col {
-        Identity::Unary(a) => {
-            let column_a =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &a.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column A:1"));
-            ValueTuple::One(model.get(column_a))
-        }
-        Identity::Binary(a, b) => {
-            let column_a =
+    let values: Vec<Value> = target_col
+        .iter()
+        .map(|col| {
+            let column =
                 <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &a.to_string(),
+                    &col.to_string(),
                 )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column A:2"));
-            let column_b =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &b.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column B:2"));
-            ValueTuple::Two(model.get(column_a), model.get(column_b))
-        }
-        Identity::Ternary(a, b, c) => {
-            let column_a =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &a.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column A:3"));
-            let column_b =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &b.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column B:3"));
-            let column_c =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &c.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column C:3"));
-            ValueTuple::Three(
-                model.get(column_a),
-                model.get(column_b),
-                model.get(column_c),
-            )
-        }
+                .unwrap_or_else(|_| panic!("Failed at mapping string to column {col:?}"));
+            model.get(column)
+        })
+        .collect();
+    let mut values = values.into_iter();
+    macro_rules! next {
+        () => {
+            values.next().expect("Failed at extracting key value")
+        };
+    }
+    match target_col.arity() {
+        1 => ValueTuple::One(next!()),
+        2 => ValueTuple::Two(next!(), next!()),
+        3 => ValueTuple::Three(next!(), next!(), next!()),
+        4 => ValueTuple::Four(next!(), next!(), next!(), next!()),
+        5 => ValueTuple::Five(next!(), next!(), next!(), next!(), next!()),
+        6 => ValueTuple::Six(next!(), next!(), next!(), next!(), next!(), next!()),
+        _ => panic!("The arity cannot be larger than 6"),
     }
 }
 
@@ -394,19 +910,11 @@ fn prepare_condition(table: &TableRef, col: &Identity, keys: &[ValueTuple]) -> C
             let column_a = table_column(table, column_a);
             Condition::all().add(Expr::col(column_a).is_in(keys.into_iter().flatten()))
         }
-        Identity::Binary(column_a, column_b) => Condition::all().add(
-            Expr::tuple([
-                SimpleExpr::Column(table_column(table, column_a)),
-                SimpleExpr::Column(table_column(table, column_b)),
-            ])
-            .in_tuples(keys),
-        ),
-        Identity::Ternary(column_a, column_b, column_c) => Condition::all().add(
-            Expr::tuple([
-                SimpleExpr::Column(table_column(table, column_a)),
-                SimpleExpr::Column(table_column(table, column_b)),
-                SimpleExpr::Column(table_column(table, column_c)),
-            ])
+        _ => Condition::all().add(
+            Expr::tuple(
+                col.iter()
+                    .map(|column| SimpleExpr::Column(table_column(table, column))),
+            )
             .in_tuples(keys),
         ),
     }
@@ -506,4 +1014,482 @@ mod tests {
             ]
         );
     }
+
+    #[tokio::test]
+    async fn test_load_via() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTrait, MockDatabase, Transaction,
+        };
+
+        let row = maplit::btreemap! {
+            "A_id" => Into::<Value>::into(1),
+            "A_name" => Into::<Value>::into("Marmalade"),
+            "A_vendor_id" => Into::<Value>::into(Some(1)),
+            "B_id" => Into::<Value>::into(2),
+            "B_name" => Into::<Value>::into("London Cheese"),
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[row.clone()], [row]])
+            .into_connection();
+
+        let cakes = vec![
+            cake::Model {
+                id: 1,
+                name: "New York Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "London Cheese".to_owned(),
+            },
+        ];
+
+        let marmalade = filling::Model {
+            id: 1,
+            name: "Marmalade".to_owned(),
+            vendor_id: Some(1),
+            ignored_attr: 0,
+        };
+
+        let fillings = cakes
+            .load_many(filling::Entity::find(), &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(fillings, [vec![], vec![marmalade.clone()]]);
+
+        let filling = cakes
+            .load_one(filling::Entity::find(), &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(filling, [None, Some(marmalade)]);
+
+        let stmt = Transaction::from_sql_and_values(
+            DbBackend::Postgres,
+            [
+                r#"SELECT "filling"."id" AS "A_id", "filling"."name" AS "A_name", "filling"."vendor_id" AS "A_vendor_id","#,
+                r#""r1"."id" AS "B_id", "r1"."name" AS "B_name""#,
+                r#"FROM "filling""#,
+                r#"INNER JOIN "cake_filling" AS "r0" ON "r0"."filling_id" = "filling"."id""#,
+                r#"INNER JOIN "cake" AS "r1" ON "r1"."id" = "r0"."cake_id""#,
+                r#"WHERE "r1"."id" IN ($1, $2)"#,
+            ]
+            .join(" ")
+            .as_str(),
+            [1i32.into(), 2i32.into()],
+        );
+        assert_eq!(db.into_transaction_log(), [stmt.clone(), stmt]);
+    }
+
+    #[tokio::test]
+    async fn test_load_nested() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, IntoMockRow, LoadMany, LoadManyToMany,
+            LoadOne, LoaderTrait, MockDatabase,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[fruit::Model {
+                id: 1,
+                name: "Apple".to_owned(),
+                cake_id: Some(1),
+            }
+            .into_mock_row()]])
+            .append_query_results([[
+                cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 1,
+                }
+                .into_mock_row(),
+                cake_filling::Model {
+                    cake_id: 2,
+                    filling_id: 1,
+                }
+                .into_mock_row(),
+            ]])
+            .append_query_results([[filling::Model {
+                id: 1,
+                name: "Chocolate".to_owned(),
+                vendor_id: Some(1),
+                ignored_attr: 0,
+            }
+            .into_mock_row()]])
+            .append_query_results([[vendor::Model {
+                id: 1,
+                name: "Cocoa Inc".to_owned(),
+            }
+            .into_mock_row()]])
+            .into_connection();
+
+        let cakes = vec![
+            cake::Model {
+                id: 1,
+                name: "New York Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "London Cheese".to_owned(),
+            },
+        ];
+
+        let loaded = cakes
+            .load_nested(
+                (
+                    LoadMany::new(fruit::Entity),
+                    LoadManyToMany::new(filling::Entity, cake_filling::Entity)
+                        .with(LoadOne::new(vendor::Entity)),
+                ),
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        let chocolate = || {
+            (
+                filling::Model {
+                    id: 1,
+                    name: "Chocolate".to_owned(),
+                    vendor_id: Some(1),
+                    ignored_attr: 0,
+                },
+                Some(vendor::Model {
+                    id: 1,
+                    name: "Cocoa Inc".to_owned(),
+                }),
+            )
+        };
+
+        assert_eq!(
+            loaded,
+            [
+                (
+                    cakes[0].clone(),
+                    (
+                        vec![fruit::Model {
+                            id: 1,
+                            name: "Apple".to_owned(),
+                            cake_id: Some(1),
+                        }],
+                        vec![chocolate()]
+                    )
+                ),
+                (cakes[1].clone(), (vec![], vec![chocolate()])),
+            ]
+        );
+
+        assert_eq!(db.into_transaction_log().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_load_linked() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTrait, MockDatabase, Transaction,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                maplit::btreemap! {
+                    "A_id" => Into::<Value>::into(1),
+                    "A_name" => Into::<Value>::into("Cocoa Inc"),
+                    "B_id" => Into::<Value>::into(2),
+                    "B_name" => Into::<Value>::into("London Cheese"),
+                },
+                maplit::btreemap! {
+                    "A_id" => Into::<Value>::into(1),
+                    "A_name" => Into::<Value>::into("Cocoa Inc"),
+                    "B_id" => Into::<Value>::into(3),
+                    "B_name" => Into::<Value>::into("Chocolate Forest"),
+                },
+            ]])
+            .into_connection();
+
+        let cakes = vec![
+            cake::Model {
+                id: 1,
+                name: "New York Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "London Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 3,
+                name: "Chocolate Forest".to_owned(),
+            },
+        ];
+
+        let vendors = cakes
+            .load_linked(
+                entity_linked::CheeseCakeToFillingVendor,
+                vendor::Entity::find(),
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        let cocoa = vendor::Model {
+            id: 1,
+            name: "Cocoa Inc".to_owned(),
+        };
+
+        assert_eq!(vendors, [vec![], vec![cocoa.clone()], vec![cocoa]]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "vendor"."id" AS "A_id", "vendor"."name" AS "A_name","#,
+                    r#""r2"."id" AS "B_id", "r2"."name" AS "B_name""#,
+                    r#"FROM "vendor""#,
+                    r#"INNER JOIN "filling" AS "r0" ON "r0"."vendor_id" = "vendor"."id""#,
+                    r#"INNER JOIN "cake_filling" AS "r1" ON "r1"."filling_id" = "r0"."id""#,
+                    r#"INNER JOIN "cake" AS "r2" ON "r2"."id" = "r1"."cake_id" AND "r2"."name" LIKE $1"#,
+                    r#"WHERE "r2"."id" IN ($2, $3, $4)"#,
+                ]
+                .join(" ")
+                .as_str(),
+                ["%cheese%".into(), 1i32.into(), 2i32.into(), 3i32.into()]
+            )]
+        );
+    }
+
+    mod parent {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "parent")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub a: i32,
+            #[sea_orm(primary_key)]
+            pub b: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(has_many = "super::child::Entity")]
+            Child,
+        }
+
+        impl Related<super::child::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Child.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod child {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "child")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub parent_a: i32,
+            pub parent_b: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "super::parent::Entity",
+                from = "(Column::ParentA, Column::ParentB)",
+                to = "(super::parent::Column::A, super::parent::Column::B)"
+            )]
+            Parent,
+        }
+
+        impl Related<super::parent::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Parent.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[tokio::test]
+    async fn test_load_many_composite_key() {
+        use crate::{
+            entity::prelude::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase, Transaction,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                child::Model {
+                    id: 1,
+                    parent_a: 1,
+                    parent_b: 2,
+                }
+                .into_mock_row(),
+                child::Model {
+                    id: 2,
+                    parent_a: 1,
+                    parent_b: 2,
+                }
+                .into_mock_row(),
+            ]])
+            .into_connection();
+
+        let parents = vec![parent::Model { a: 1, b: 2 }, parent::Model { a: 3, b: 4 }];
+
+        let children = parents
+            .load_many(child::Entity, &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            children,
+            [
+                vec![
+                    child::Model {
+                        id: 1,
+                        parent_a: 1,
+                        parent_b: 2,
+                    },
+                    child::Model {
+                        id: 2,
+                        parent_a: 1,
+                        parent_b: 2,
+                    },
+                ],
+                vec![],
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "child"."id", "child"."parent_a", "child"."parent_b""#,
+                    r#"FROM "child""#,
+                    r#"WHERE ("child"."parent_a", "child"."parent_b") IN (($1, $2), ($3, $4))"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into(), 2i32.into(), 3i32.into(), 4i32.into()]
+            )]
+        );
+    }
+
+    mod tenant {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "tenant")]
+        pub struct Model {
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub a: i32,
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub b: i32,
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub c: i32,
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub d: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod account {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "account")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub tenant_a: i32,
+            pub tenant_b: i32,
+            pub tenant_c: i32,
+            pub tenant_d: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "super::tenant::Entity",
+                from = "(Column::TenantA, Column::TenantB, Column::TenantC, Column::TenantD)",
+                to = "(super::tenant::Column::A, super::tenant::Column::B, super::tenant::Column::C, super::tenant::Column::D)"
+            )]
+            Tenant,
+        }
+
+        impl Related<super::tenant::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Tenant.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[tokio::test]
+    async fn test_load_one_composite_key_4() {
+        use crate::{
+            entity::prelude::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase, Transaction,
+        };
+
+        let tenant = tenant::Model {
+            a: 1,
+            b: 2,
+            c: 3,
+            d: 4,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[tenant.clone().into_mock_row()]])
+            .into_connection();
+
+        let accounts = vec![
+            account::Model {
+                id: 1,
+                tenant_a: 1,
+                tenant_b: 2,
+                tenant_c: 3,
+                tenant_d: 4,
+            },
+            account::Model {
+                id: 2,
+                tenant_a: 5,
+                tenant_b: 6,
+                tenant_c: 7,
+                tenant_d: 8,
+            },
+        ];
+
+        let tenants = accounts
+            .load_one(tenant::Entity, &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(tenants, [Some(tenant), None]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "tenant"."a", "tenant"."b", "tenant"."c", "tenant"."d""#,
+                    r#"FROM "tenant""#,
+                    r#"WHERE ("tenant"."a", "tenant"."b", "tenant"."c", "tenant"."d") IN (($1, $2, $3, $4), ($5, $6, $7, $8))"#,
+                ]
+                .join(" ")
+                .as_str(),
+                (1..=8).map(|i: i32| i.into()).collect::<Vec<Value>>()
+            )]
+        );
+    }
 }
@@ -7,11 +7,12 @@ mod join;
 mod json;
 mod loader;
 mod select;
+pub(crate) mod soft_delete;
 mod traits;
 mod update;
 mod util;
 
-pub use combine::{SelectA, SelectB};
+pub use combine::{SelectA, SelectB, SelectC, SelectD};
 pub use delete::*;
 pub use helper::*;
 pub use insert::*;
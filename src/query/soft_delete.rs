@@ -0,0 +1,739 @@
+use crate::{
+    version_column, ColumnTrait, DbErr, EntityTrait, IdenStatic, Iterable, PrimaryKeyToColumn,
+    QueryFilter, RelationDef, Select,
+};
+use sea_query::{
+    Alias, ColumnType, Condition, DynIden, Expr, SimpleExpr, UpdateStatement, ValueTuple,
+};
+
+/// Get the column marked with `#[sea_orm(soft_delete)]`, if any
+pub(crate) fn soft_delete_column<E>() -> Option<E::Column>
+where
+    E: EntityTrait,
+{
+    E::Column::iter().find(|col| col.def().is_soft_delete())
+}
+
+/// A boolean flag is set to `true` on deletion, otherwise the column is
+/// treated as a nullable timestamp and set to the current timestamp
+fn is_flag<C>(col: &C) -> bool
+where
+    C: ColumnTrait,
+{
+    matches!(col.def().get_column_type(), ColumnType::Boolean)
+}
+
+fn not_deleted_expr(expr: Expr, flag: bool) -> SimpleExpr {
+    if flag {
+        expr.eq(false)
+    } else {
+        expr.is_null()
+    }
+}
+
+fn deleted_expr(expr: Expr, flag: bool) -> SimpleExpr {
+    if flag {
+        expr.eq(true)
+    } else {
+        expr.is_not_null()
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Exclude the rows that have been soft deleted
+    pub(crate) fn filter_not_deleted(self) -> Self {
+        match soft_delete_column::<E>() {
+            Some(col) => {
+                let expr = Expr::col((col.entity_name(), col));
+                self.filter(not_deleted_expr(expr, is_flag(&col)))
+            }
+            None => self,
+        }
+    }
+
+    /// Only keep the rows that have been soft deleted
+    pub(crate) fn filter_deleted(self) -> Self {
+        match soft_delete_column::<E>() {
+            Some(col) => {
+                let expr = Expr::col((col.entity_name(), col));
+                self.filter(deleted_expr(expr, is_flag(&col)))
+            }
+            None => self,
+        }
+    }
+}
+
+/// Find a model by primary key, even if it has been soft deleted, e.g. to select back
+/// a row that has just been inserted or updated
+pub(crate) fn find_with_deleted_by_id<E>(values: ValueTuple) -> Select<E>
+where
+    E: EntityTrait,
+{
+    let mut select = E::with_deleted();
+    for (key, value) in E::PrimaryKey::iter().zip(values) {
+        select = select.filter(key.into_column().eq(value));
+    }
+    select
+}
+
+/// Exclude the soft deleted rows of the Entity on the `to` side of a join
+pub(crate) fn join_not_deleted<E>(mut rel: RelationDef) -> RelationDef
+where
+    E: EntityTrait,
+{
+    let col = match soft_delete_column::<E>() {
+        Some(col) => col,
+        None => return rel,
+    };
+    let name = col.as_str().to_owned();
+    let flag = is_flag(&col);
+    let on_condition = rel.on_condition.take();
+    rel.on_condition = Some(Box::new(move |from_tbl: DynIden, to_tbl: DynIden| {
+        let mut condition = Condition::all();
+        if let Some(f) = &on_condition {
+            condition = condition.add(f(from_tbl, to_tbl.clone()));
+        }
+        let expr = Expr::col((to_tbl, Alias::new(&name)));
+        condition.add(not_deleted_expr(expr, flag))
+    }));
+    rel
+}
+
+/// The conditions of a DELETE statement, to be carried over to the UPDATE statement
+/// of a soft delete
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SoftDelete {
+    conditions: Vec<Condition>,
+    /// Whether the DELETE statement has been accessed through `query()`, in which case
+    /// it may have changed in a way that cannot be carried over
+    query_changed: bool,
+}
+
+impl SoftDelete {
+    pub(crate) fn add_condition(&mut self, condition: Condition) {
+        self.conditions.push(condition);
+    }
+
+    pub(crate) fn query_changed(&mut self) {
+        self.query_changed = true;
+    }
+}
+
+/// Build the UPDATE statement marking the rows matched by the DELETE statement as deleted.
+/// Returns `None` if the rows are to be deleted for real.
+///
+/// Only the conditions added with `filter` can be carried over to the UPDATE statement,
+/// so the DELETE statement is refused if it has been changed through `query()`.
+pub(crate) fn soft_delete_stmt<E>(
+    soft_delete: Option<&SoftDelete>,
+) -> Result<Option<UpdateStatement>, DbErr>
+where
+    E: EntityTrait,
+{
+    let (col, soft_delete) = match (soft_delete_column::<E>(), soft_delete) {
+        (Some(col), Some(soft_delete)) => (col, soft_delete),
+        _ => return Ok(None),
+    };
+    if soft_delete.query_changed {
+        return Err(DbErr::Custom(
+            "The DELETE statement has been changed through `query()` and cannot be turned into a soft delete, use `force_delete` to delete the rows for real".to_owned(),
+        ));
+    }
+
+    let flag = is_flag(&col);
+    let value = if flag {
+        Expr::val(true)
+    } else {
+        Expr::current_timestamp()
+    };
+    let mut stmt = UpdateStatement::new();
+    stmt.table(E::default().table_ref())
+        .value(col, col.save_as(value));
+    for condition in soft_delete.conditions.iter() {
+        stmt.cond_where(condition.clone());
+    }
+    stmt.and_where(not_deleted_expr(Expr::col((col.entity_name(), col)), flag));
+    if let Some(version) = version_column::<E>() {
+        stmt.value(version, Expr::col(version).add(1));
+    }
+    Ok(Some(stmt))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entity::prelude::*, *};
+    use pretty_assertions::assert_eq;
+
+    mod author {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "author")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub name: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(has_many = "super::post::Entity")]
+            Post,
+        }
+
+        impl Related<super::post::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Post.def()
+            }
+        }
+
+        #[derive(Debug)]
+        pub struct AuthorToPost;
+
+        impl Linked for AuthorToPost {
+            type FromEntity = Entity;
+
+            type ToEntity = super::post::Entity;
+
+            fn link(&self) -> Vec<RelationDef> {
+                vec![Relation::Post.def()]
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod post {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "post")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub title: String,
+            pub author_id: i32,
+            #[sea_orm(soft_delete)]
+            pub deleted: bool,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "super::author::Entity",
+                from = "Column::AuthorId",
+                to = "super::author::Column::Id"
+            )]
+            Author,
+        }
+
+        impl Related<super::author::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Author.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[cfg(feature = "with-chrono")]
+    mod archive {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "archive")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(soft_delete)]
+            pub deleted_at: Option<DateTimeUtc>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    /// Takes the place of `tests_cfg::filling`, to be joined through `tests_cfg::cake_filling`
+    mod filling {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+        use crate::tests_cfg::{cake, cake_filling};
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "filling")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub name: String,
+            #[sea_orm(soft_delete)]
+            pub deleted: bool,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl Related<Entity> for cake::Entity {
+            fn to() -> RelationDef {
+                cake_filling::Relation::Filling.def()
+            }
+
+            fn via() -> Option<RelationDef> {
+                Some(cake_filling::Relation::Cake.def().rev())
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn find_soft_deleted() {
+        assert_eq!(
+            post::Entity::find().build(DbBackend::Postgres).to_string(),
+            [
+                r#"SELECT "post"."id", "post"."title", "post"."author_id", "post"."deleted""#,
+                r#"FROM "post" WHERE "post"."deleted" = FALSE"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            post::Entity::find_by_id(1)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "post"."id", "post"."title", "post"."author_id", "post"."deleted""#,
+                r#"FROM "post" WHERE "post"."deleted" = FALSE AND "post"."id" = 1"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            post::Entity::with_deleted()
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "post"."id", "post"."title", "post"."author_id", "post"."deleted" FROM "post""#
+        );
+
+        assert_eq!(
+            post::Entity::only_deleted()
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "post"."id", "post"."title", "post"."author_id", "post"."deleted""#,
+                r#"FROM "post" WHERE "post"."deleted" = TRUE"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            author::Entity::with_deleted()
+                .build(DbBackend::Postgres)
+                .to_string(),
+            author::Entity::find()
+                .build(DbBackend::Postgres)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn find_related_soft_deleted() {
+        let author = author::Model {
+            id: 1,
+            name: "Alice".to_owned(),
+        };
+
+        assert_eq!(
+            author
+                .find_related(post::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "post"."id", "post"."title", "post"."author_id", "post"."deleted""#,
+                r#"FROM "post" INNER JOIN "author" ON "author"."id" = "post"."author_id""#,
+                r#"WHERE "post"."deleted" = FALSE AND "author"."id" = 1"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            author
+                .find_linked(author::AuthorToPost)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "post"."id", "post"."title", "post"."author_id", "post"."deleted""#,
+                r#"FROM "post" INNER JOIN "author" AS "r0" ON "r0"."id" = "post"."author_id""#,
+                r#"WHERE "post"."deleted" = FALSE AND "r0"."id" = 1"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            author::Entity::find()
+                .find_with_related(post::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "author"."id" AS "A_id", "author"."name" AS "A_name","#,
+                r#""post"."id" AS "B_id", "post"."title" AS "B_title", "post"."author_id" AS "B_author_id", "post"."deleted" AS "B_deleted""#,
+                r#"FROM "author" LEFT JOIN "post" ON "author"."id" = "post"."author_id" AND "post"."deleted" = FALSE"#,
+                r#"ORDER BY "author"."id" ASC"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            tests_cfg::cake::Entity::find()
+                .find_also_related(filling::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                r#""filling"."id" AS "B_id", "filling"."name" AS "B_name", "filling"."deleted" AS "B_deleted""#,
+                r#"FROM "cake" LEFT JOIN "cake_filling" ON "cake"."id" = "cake_filling"."cake_id""#,
+                r#"LEFT JOIN "filling" ON "cake_filling"."filling_id" = "filling"."id" AND "filling"."deleted" = FALSE"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[smol_potat::test]
+    async fn delete_soft_deleted() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 2,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        let post = post::Model {
+            id: 1,
+            title: "Hello".to_owned(),
+            author_id: 1,
+            deleted: false,
+        };
+
+        assert_eq!(post.clone().delete(&db).await?.rows_affected, 1);
+
+        assert_eq!(
+            post::Entity::delete_many()
+                .filter(post::Column::AuthorId.eq(1))
+                .exec(&db)
+                .await?
+                .rows_affected,
+            2
+        );
+
+        assert_eq!(
+            post::Entity::delete_by_id(2)
+                .force_delete()
+                .exec(&db)
+                .await?
+                .rows_affected,
+            1
+        );
+
+        assert_eq!(
+            post.into_active_model()
+                .force_delete(&db)
+                .await?
+                .rows_affected,
+            1
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"UPDATE "post" SET "deleted" = $1"#,
+                        r#"WHERE "post"."id" = $2 AND "post"."deleted" = $3"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [true.into(), 1i32.into(), false.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"UPDATE "post" SET "deleted" = $1"#,
+                        r#"WHERE "post"."author_id" = $2 AND "post"."deleted" = $3"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [true.into(), 1i32.into(), false.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "post" WHERE "post"."id" = $1"#,
+                    [2i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "post" WHERE "post"."id" = $1"#,
+                    [1i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn build_soft_delete() {
+        assert_eq!(
+            post::Entity::delete_by_id(1)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"UPDATE "post" SET "deleted" = TRUE WHERE "post"."id" = 1 AND "post"."deleted" = FALSE"#
+        );
+        assert_eq!(
+            post::Entity::delete_by_id(1)
+                .force_delete()
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"DELETE FROM "post" WHERE "post"."id" = 1"#
+        );
+    }
+
+    #[smol_potat::test]
+    async fn delete_soft_deleted_changed_query() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let mut delete = post::Entity::delete_many().filter(post::Column::AuthorId.eq(1));
+        QueryFilter::query(&mut delete).and_where(post::Column::Title.eq("Hello"));
+        assert!(matches!(delete.exec(&db).await, Err(DbErr::Custom(_))));
+
+        let mut delete = post::Entity::delete_many();
+        QueryFilter::query(&mut delete).and_where(post::Column::Title.eq("Hello"));
+        assert_eq!(delete.force_delete().exec(&db).await?.rows_affected, 1);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"DELETE FROM "post" WHERE "post"."title" = $1"#,
+                ["Hello".into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_soft_deleted_with_returning() -> Result<(), DbErr> {
+        let post = post::Model {
+            id: 1,
+            title: "Hello".to_owned(),
+            author_id: 1,
+            deleted: true,
+        };
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 1,
+                rows_affected: 1,
+            }])
+            .append_query_results([[post.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            post::Entity::insert_many([post.clone().into_active_model()])
+                .exec_with_returning_many(&db)
+                .await?,
+            [post]
+        );
+
+        assert_eq!(
+            db.into_transaction_log()[1],
+            Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    r#"SELECT `post`.`id`, `post`.`title`, `post`.`author_id`, `post`.`deleted`"#,
+                    r#"FROM `post` WHERE `post`.`id` IN (?) ORDER BY `post`.`id` ASC"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into()]
+            )
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_one_soft_deleted() -> Result<(), DbErr> {
+        let post = post::Model {
+            id: 1,
+            title: "Hello".to_owned(),
+            author_id: 1,
+            deleted: true,
+        };
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 1,
+                rows_affected: 1,
+            }])
+            .append_query_results([[post.clone()]])
+            .into_connection();
+
+        assert_eq!(post.clone().into_active_model().insert(&db).await?, post);
+
+        assert_eq!(
+            db.into_transaction_log()[1],
+            Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    r#"SELECT `post`.`id`, `post`.`title`, `post`.`author_id`, `post`.`deleted`"#,
+                    r#"FROM `post` WHERE `post`.`id` = ? LIMIT ?"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into(), 1u64.into()]
+            )
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn update_soft_deleted() -> Result<(), DbErr> {
+        let post = post::Model {
+            id: 1,
+            title: "Hello".to_owned(),
+            author_id: 1,
+            deleted: true,
+        };
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_query_results([[post.clone()]])
+            .into_connection();
+
+        let mut active_model = post.clone().into_active_model();
+        active_model.title = Set("Hello".to_owned());
+        active_model.reset(post::Column::Title);
+        assert_eq!(active_model.update(&db).await?, post);
+
+        assert_eq!(
+            db.into_transaction_log()[1],
+            Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    r#"SELECT `post`.`id`, `post`.`title`, `post`.`author_id`, `post`.`deleted`"#,
+                    r#"FROM `post` WHERE `post`.`id` = ? LIMIT ?"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into(), 1u64.into()]
+            )
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn load_soft_deleted() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([Vec::<post::Model>::new()])
+            .into_connection();
+
+        let authors = vec![author::Model {
+            id: 1,
+            name: "Alice".to_owned(),
+        }];
+
+        assert_eq!(
+            authors.load_many(post::Entity, &db).await?,
+            vec![Vec::<post::Model>::new()]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "post"."id", "post"."title", "post"."author_id", "post"."deleted""#,
+                    r#"FROM "post" WHERE "post"."deleted" = $1 AND "post"."author_id" IN ($2)"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [false.into(), 1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "with-chrono")]
+    #[smol_potat::test]
+    async fn soft_delete_timestamp() -> Result<(), DbErr> {
+        assert_eq!(
+            archive::Entity::find()
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "archive"."id", "archive"."deleted_at" FROM "archive""#,
+                r#"WHERE "archive"."deleted_at" IS NULL"#,
+            ]
+            .join(" ")
+        );
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        archive::Entity::delete_by_id(1).exec(&db).await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"UPDATE "archive" SET "deleted_at" = CURRENT_TIMESTAMP"#,
+                    r#"WHERE "archive"."id" = $1 AND "archive"."deleted_at" IS NULL"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@ pub trait QueryTrait {
             db_backend,
             self.as_query().build_any(query_builder.as_ref()),
         )
+        .with_table_of(self.as_query())
     }
 
     /// Apply an operation on the [QueryTrait::QueryStatement] if the given `Option<T>` is `Some(_)`
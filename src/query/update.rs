@@ -1,9 +1,9 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, Iterable, PrimaryKeyToColumn,
-    QueryFilter, QueryTrait,
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IdenStatic, Iterable,
+    PrimaryKeyToColumn, QueryFilter, QueryTrait,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, IntoIden, SimpleExpr, UpdateStatement};
+use sea_query::{Expr, IntoIden, SimpleExpr, UpdateStatement, Value};
 
 /// Defines a structure to perform UPDATE query operations on a ActiveModel
 #[derive(Clone, Debug)]
@@ -99,14 +99,21 @@ where
                 ActiveValue::NotSet => panic!("PrimaryKey is not set"),
             }
         }
+        if let Some((col, value)) = version_of(&self.model) {
+            self = self.filter(col.eq(value));
+        }
         self
     }
 
     fn prepare_values(mut self) -> Self {
+        let version = version_column::<A::Entity>();
         for col in <A::Entity as EntityTrait>::Column::iter() {
             if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some() {
                 continue;
             }
+            if matches!(version, Some(v) if v.as_str() == col.as_str()) {
+                continue;
+            }
             match self.model.get(col) {
                 ActiveValue::Set(value) => {
                     let expr = col.save_as(Expr::val(value));
@@ -115,10 +122,77 @@ where
                 ActiveValue::Unchanged(_) | ActiveValue::NotSet => {}
             }
         }
+        // only stamp and bump the version when something is actually being updated
+        if !self.query.get_values().is_empty() {
+            for col in <A::Entity as EntityTrait>::Column::iter() {
+                if !col.def().is_updated_at() || self.model.get(col).is_set() {
+                    continue;
+                }
+                if let Some(value) = col.auto_timestamp() {
+                    self.query.value(col, col.save_as(Expr::val(value)));
+                }
+            }
+            if let Some(col) = version {
+                self.query.value(col, Expr::col(col).add(1));
+            }
+        }
         self
     }
 }
 
+impl<E> UpdateMany<E>
+where
+    E: EntityTrait,
+{
+    /// Stamp the `updated_at` columns and bump the version column, unless they have been set explicitly
+    pub(crate) fn prepare_auto_values(mut self) -> Self {
+        if self.query.get_values().is_empty() {
+            return self;
+        }
+        let is_set = |query: &UpdateStatement, col: E::Column| {
+            query
+                .get_values()
+                .iter()
+                .any(|(iden, _)| iden.to_string() == col.as_str())
+        };
+        for col in E::Column::iter() {
+            if !col.def().is_updated_at() || is_set(&self.query, col) {
+                continue;
+            }
+            if let Some(value) = col.auto_timestamp() {
+                self.query.value(col, col.save_as(Expr::val(value)));
+            }
+        }
+        if let Some(col) = version_column::<E>() {
+            if !is_set(&self.query, col) {
+                self.query.value(col, Expr::col(col).add(1));
+            }
+        }
+        self
+    }
+}
+
+/// Get the column marked with `#[sea_orm(version)]`, if any
+pub(crate) fn version_column<E>() -> Option<E::Column>
+where
+    E: EntityTrait,
+{
+    E::Column::iter().find(|col| col.def().is_version())
+}
+
+/// Get the version column and the version value held by the ActiveModel,
+/// if the Entity is versioned and the value is known
+pub(crate) fn version_of<A>(model: &A) -> Option<(<A::Entity as EntityTrait>::Column, Value)>
+where
+    A: ActiveModelTrait,
+{
+    let col = version_column::<A::Entity>()?;
+    match model.get(col) {
+        ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Some((col, value)),
+        ActiveValue::NotSet => None,
+    }
+}
+
 impl<A> QueryFilter for UpdateOne<A>
 where
     A: ActiveModelTrait,
@@ -328,4 +402,90 @@ mod tests {
             r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS tea) WHERE "lunch_set"."id" = 1"#,
         );
     }
+
+    #[test]
+    #[cfg(all(feature = "with-chrono", feature = "with-time"))]
+    fn update_timestamps() {
+        mod stamped {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "stamped")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub name: String,
+                #[sea_orm(created_at)]
+                pub created_at: DateTimeUtc,
+                #[sea_orm(updated_at)]
+                pub updated_at: Option<TimeDateTimeWithTimeZone>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let is_timestamp = |value: &Value| {
+            matches!(
+                value,
+                Value::ChronoDateTimeUtc(Some(_)) | Value::TimeDateTimeWithTimeZone(Some(_))
+            )
+        };
+
+        let stmt = Insert::many([
+            stamped::ActiveModel {
+                name: Set("A".to_owned()),
+                ..Default::default()
+            },
+            stamped::ActiveModel {
+                name: Set("B".to_owned()),
+                ..Default::default()
+            },
+        ])
+        .build(DbBackend::Postgres);
+        assert_eq!(
+            stmt.sql,
+            r#"INSERT INTO "stamped" ("name", "created_at", "updated_at") VALUES ($1, $2, $3), ($4, $5, $6)"#
+        );
+        let values = stmt.values.expect("values").0;
+        assert!(is_timestamp(&values[1]) && is_timestamp(&values[2]));
+        assert!(is_timestamp(&values[4]) && is_timestamp(&values[5]));
+
+        let model = stamped::Model {
+            id: 1,
+            name: "A".to_owned(),
+            created_at: "2023-01-01T00:00:00Z".parse().expect("datetime"),
+            updated_at: None,
+        };
+
+        let stmt = Update::one(stamped::ActiveModel {
+            name: Set("C".to_owned()),
+            ..model.clone().into_active_model()
+        })
+        .build(DbBackend::Postgres);
+        assert_eq!(
+            stmt.sql,
+            r#"UPDATE "stamped" SET "name" = $1, "updated_at" = $2 WHERE "stamped"."id" = $3"#
+        );
+        assert!(is_timestamp(&stmt.values.expect("values").0[1]));
+
+        // nothing to update, so nothing is stamped
+        assert!(Update::one(model.into_active_model())
+            .as_query()
+            .get_values()
+            .is_empty());
+
+        let stmt = Update::many(stamped::Entity)
+            .col_expr(stamped::Column::Name, Expr::value("D"))
+            .prepare_auto_values()
+            .build(DbBackend::Postgres);
+        assert_eq!(
+            stmt.sql,
+            r#"UPDATE "stamped" SET "name" = $1, "updated_at" = $2"#
+        );
+        assert!(is_timestamp(&stmt.values.expect("values").0[1]));
+    }
 }
@@ -75,6 +75,12 @@ impl RelationTrait for Relation {
     }
 }
 
+impl Related<super::vendor::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Vendor.def()
+    }
+}
+
 impl Related<super::cake::Entity> for Entity {
     fn to() -> RelationDef {
         super::cake_filling::Relation::Cake.def()
@@ -0,0 +1,408 @@
+#[cfg(feature = "tracing-spans")]
+use crate::{metric::RowCount, DbBackend, DbErr, Statement};
+#[cfg(feature = "tracing-spans")]
+use sea_query::{
+    EscapeBuilder, QueryBuilder, QueryStatementBuilder, QuotedBuilder, SimpleExpr, SqlWriter,
+    SubQueryStatement, TableRef, TableRefBuilder, Value,
+};
+#[cfg(feature = "tracing-spans")]
+use std::cell::RefCell;
+#[cfg(feature = "tracing-spans")]
+use tracing::{field::Empty, Span};
+
+#[allow(unused_imports)]
+pub(crate) use inner::db_span;
+
+#[cfg(feature = "tracing-spans")]
+mod inner {
+    /// Run the code within a span describing the statement, and record its result on it.
+    /// The statement is given as is, or as the backend and SQL of an unprepared one.
+    /// This must be enabled using the feature flag `tracing-spans`.
+    #[allow(unused_macros)]
+    macro_rules! db_span {
+        ($stmt:expr, $code:block) => {{
+            let span = crate::tracing_spans::query_span($stmt, None);
+            let mut res = tracing::Instrument::instrument(async $code, span.clone()).await;
+            crate::tracing_spans::record(&span, &mut res);
+            res
+        }};
+        ($stmt:expr, parent: $parent:expr, $code:block) => {{
+            let span = crate::tracing_spans::query_span($stmt, Some($parent));
+            let mut res = tracing::Instrument::instrument(async $code, span.clone()).await;
+            crate::tracing_spans::record(&span, &mut res);
+            res
+        }};
+        ($backend:expr, $sql:expr, $code:block) => {
+            crate::tracing_spans::db_span!(
+                &crate::Statement::from_string($backend, $sql.to_string()),
+                $code
+            )
+        };
+        ($backend:expr, $sql:expr, parent: $parent:expr, $code:block) => {
+            crate::tracing_spans::db_span!(
+                &crate::Statement::from_string($backend, $sql.to_string()),
+                parent: $parent,
+                $code
+            )
+        };
+    }
+    pub(crate) use db_span;
+}
+
+#[cfg(not(feature = "tracing-spans"))]
+mod inner {
+    #[allow(unused_macros)]
+    macro_rules! db_span {
+        ($stmt:expr, $code:block) => {
+            $code
+        };
+        ($stmt:expr, parent: $parent:expr, $code:block) => {
+            $code
+        };
+        ($backend:expr, $sql:expr, $code:block) => {
+            $code
+        };
+        ($backend:expr, $sql:expr, parent: $parent:expr, $code:block) => {
+            $code
+        };
+    }
+    pub(crate) use db_span;
+}
+
+/// A span covering a statement, following the database semantic conventions of OpenTelemetry.
+/// It is nested in the given parent span, or else in the current one.
+/// The table is the one recorded by the query builder, or else the one found in the SQL.
+#[cfg(feature = "tracing-spans")]
+pub(crate) fn query_span(stmt: &Statement, parent: Option<&Span>) -> Span {
+    let sql = stmt.sql.as_str();
+    let operation = operation(sql);
+    let table = stmt.table.clone().or_else(|| table(sql));
+    tracing::info_span!(
+        parent: parent.map_or_else(Span::current, Clone::clone).id(),
+        "db.query",
+        otel.name = %table
+            .as_ref()
+            .map_or_else(|| operation.to_owned(), |table| format!("{operation} {table}")),
+        otel.kind = "client",
+        otel.status_code = Empty,
+        db.system = db_system(stmt.db_backend),
+        db.statement = sql,
+        db.operation = operation,
+        db.sql.table = table.as_deref(),
+        db.rows_affected = Empty,
+        db.rows_returned = Empty,
+        error.type = Empty,
+    )
+}
+
+/// A span covering a transaction, which the spans of its statements and nested transactions belong to
+#[cfg(feature = "tracing-spans")]
+pub(crate) fn transaction_span(backend: DbBackend, parent: Option<&Span>) -> Span {
+    tracing::info_span!(
+        parent: parent.map_or_else(Span::current, Clone::clone).id(),
+        "db.transaction",
+        otel.kind = "client",
+        db.system = db_system(backend),
+    )
+}
+
+/// Record the outcome of a statement on its span. A stream records its rows once it ends.
+#[cfg(feature = "tracing-spans")]
+pub(crate) fn record<T>(span: &Span, res: &mut Result<T, DbErr>)
+where
+    T: RowCount,
+{
+    match res {
+        Ok(res) => {
+            if let Some(rows_affected) = res.rows_affected() {
+                span.record("db.rows_affected", rows_affected);
+            }
+            if let Some(rows_returned) = res.rows_returned() {
+                span.record("db.rows_returned", rows_returned);
+            }
+            res.trace(span);
+        }
+        Err(err) => record_error(span, err),
+    }
+}
+
+/// Record the failure of a statement on its span
+#[cfg(feature = "tracing-spans")]
+pub(crate) fn record_error(span: &Span, err: &DbErr) {
+    span.record("otel.status_code", "ERROR");
+    span.record("error.type", error_type(err));
+}
+
+/// The name of the table a query statement operates on, i.e. the first table it refers to
+/// outside of any expression, as `schema.table` if it's qualified
+#[cfg(feature = "tracing-spans")]
+pub(crate) fn table_of<S>(query: &S) -> Option<String>
+where
+    S: QueryStatementBuilder,
+{
+    let recorder = TableRecorder::default();
+    query.build_collect_any_into(&recorder, &mut String::new());
+    recorder.table.into_inner()
+}
+
+/// A query builder walking a statement for its first table, skipping the expressions
+#[cfg(feature = "tracing-spans")]
+#[derive(Debug, Default)]
+struct TableRecorder {
+    table: RefCell<Option<String>>,
+}
+
+#[cfg(feature = "tracing-spans")]
+impl QueryBuilder for TableRecorder {
+    fn prepare_table_ref(&self, table_ref: &TableRef, _sql: &mut dyn SqlWriter) {
+        let table = match table_ref {
+            TableRef::Table(table) | TableRef::TableAlias(table, _) => table.to_string(),
+            TableRef::SchemaTable(schema, table)
+            | TableRef::SchemaTableAlias(schema, table, _)
+            | TableRef::DatabaseSchemaTable(_, schema, table)
+            | TableRef::DatabaseSchemaTableAlias(_, schema, table, _) => {
+                format!("{}.{}", schema.to_string(), table.to_string())
+            }
+            TableRef::SubQuery(..) | TableRef::ValuesList(..) | TableRef::FunctionCall(..) => {
+                return
+            }
+        };
+        self.table.borrow_mut().get_or_insert(table);
+    }
+
+    fn prepare_simple_expr(&self, _simple_expr: &SimpleExpr, _sql: &mut dyn SqlWriter) {}
+
+    fn prepare_query_statement(&self, _query: &SubQueryStatement, _sql: &mut dyn SqlWriter) {}
+
+    fn prepare_value(&self, _value: &Value, _sql: &mut dyn SqlWriter) {}
+}
+
+#[cfg(feature = "tracing-spans")]
+impl QuotedBuilder for TableRecorder {
+    fn quote(&self) -> char {
+        '"'
+    }
+}
+
+#[cfg(feature = "tracing-spans")]
+impl EscapeBuilder for TableRecorder {}
+
+#[cfg(feature = "tracing-spans")]
+impl TableRefBuilder for TableRecorder {}
+
+#[cfg(feature = "tracing-spans")]
+fn db_system(backend: DbBackend) -> &'static str {
+    match backend {
+        DbBackend::MySql => "mysql",
+        DbBackend::Postgres => "postgresql",
+        DbBackend::Sqlite => "sqlite",
+    }
+}
+
+/// The kind of error, e.g. `UniqueConstraintViolation` or `ConnectionAcquire`
+#[cfg(feature = "tracing-spans")]
+fn error_type(err: &DbErr) -> String {
+    let debug = match err.sql_err() {
+        Some(sql_err) => format!("{sql_err:?}"),
+        None => format!("{err:?}"),
+    };
+    debug
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or_default()
+        .to_owned()
+}
+
+/// The SQL keyword the statement starts with, e.g. `SELECT`
+#[cfg(any(feature = "tracing-spans", test))]
+fn operation(sql: &str) -> &str {
+    sql.split_whitespace().next().unwrap_or_default()
+}
+
+/// The name of the first table the statement operates on, without quotes
+#[cfg(any(feature = "tracing-spans", test))]
+fn table(sql: &str) -> Option<String> {
+    let mut words = sql.split_whitespace();
+    words.find(|word| {
+        ["FROM", "INTO", "UPDATE"]
+            .iter()
+            .any(|keyword| word.eq_ignore_ascii_case(keyword))
+    })?;
+    let table = words.next()?.trim_end_matches(&[',', ';', '('][..]);
+    Some(table.chars().filter(|c| !matches!(c, '"' | '`')).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{operation, table};
+    use pretty_assertions::assert_eq;
+    #[cfg(feature = "tracing-spans")]
+    use std::{
+        fmt,
+        sync::{Arc, Mutex},
+    };
+    #[cfg(feature = "tracing-spans")]
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Subscriber,
+    };
+    #[cfg(feature = "tracing-spans")]
+    use tracing_subscriber::layer::{Context, Layer};
+
+    /// The fields of every span, in the order they are recorded
+    #[cfg(feature = "tracing-spans")]
+    #[derive(Clone, Default)]
+    struct Fields(Arc<Mutex<Vec<(String, String)>>>);
+
+    #[cfg(feature = "tracing-spans")]
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .lock()
+                .expect("Fail to acquire span fields")
+                .push((field.name().to_owned(), format!("{value:?}")));
+        }
+    }
+
+    #[cfg(feature = "tracing-spans")]
+    impl<S: Subscriber> Layer<S> for Fields {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+            attrs.record(&mut self.clone());
+        }
+
+        fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+            values.record(&mut self.clone());
+        }
+    }
+
+    #[test]
+    fn operation_and_table() {
+        let cases = [
+            (
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = $1"#,
+                "SELECT",
+                Some("cake"),
+            ),
+            (
+                "INSERT INTO `fruit` (`name`) VALUES (?)",
+                "INSERT",
+                Some("fruit"),
+            ),
+            (
+                r#"UPDATE "filling" SET "name" = $1"#,
+                "UPDATE",
+                Some("filling"),
+            ),
+            (r#"DELETE FROM "cake""#, "DELETE", Some("cake")),
+            (
+                r#"SELECT COUNT(*) FROM "public"."cake""#,
+                "SELECT",
+                Some("public.cake"),
+            ),
+            (r#"SAVEPOINT "sp""#, "SAVEPOINT", None),
+        ];
+        for (sql, op, tbl) in cases {
+            assert_eq!((operation(sql), table(sql).as_deref()), (op, tbl));
+        }
+    }
+
+    #[cfg(feature = "tracing-spans")]
+    #[test]
+    fn table_of_query() {
+        use super::table_of;
+        use crate::{entity::*, query::*, tests_cfg::*, DbBackend};
+        use sea_query::{Alias, Expr, Query};
+
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related(fruit::Entity)
+                .filter(
+                    cake::Column::Id.in_subquery(
+                        Query::select()
+                            .column(fruit::Column::CakeId)
+                            .from(fruit::Entity)
+                            .to_owned()
+                    )
+                )
+                .build(DbBackend::Postgres)
+                .table
+                .as_deref(),
+            Some("cake")
+        );
+        assert_eq!(
+            table_of(
+                Insert::one(fruit::ActiveModel {
+                    name: Set("Apple".to_owned()),
+                    ..Default::default()
+                })
+                .as_query()
+            )
+            .as_deref(),
+            Some("fruit")
+        );
+        assert_eq!(
+            table_of(
+                filling::Entity::update_many()
+                    .col_expr(filling::Column::Name, Expr::value("Lemon"))
+                    .as_query()
+            )
+            .as_deref(),
+            Some("filling")
+        );
+        assert_eq!(
+            table_of(cake::Entity::delete_many().as_query()).as_deref(),
+            Some("cake")
+        );
+        assert_eq!(
+            DbBackend::Postgres
+                .build(
+                    Query::select()
+                        .column(cake::Column::Id)
+                        .from((Alias::new("public"), Alias::new("cake")))
+                )
+                .table
+                .as_deref(),
+            Some("public.cake")
+        );
+    }
+
+    #[cfg(all(feature = "tracing-spans", feature = "mock"))]
+    #[smol_potat::test]
+    async fn stream_rows_returned() -> Result<(), crate::DbErr> {
+        use super::query_span;
+        use crate::{entity::*, query::*, tests_cfg::*, DbBackend, MockDatabase, StreamTrait};
+        use futures::TryStreamExt;
+        use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                cake::Model {
+                    id: 1,
+                    name: "Cheese Cake".to_owned(),
+                },
+                cake::Model {
+                    id: 2,
+                    name: "Chocolate Cake".to_owned(),
+                },
+            ]])
+            .into_connection();
+        let fields = Fields::default();
+        let _guard = tracing_subscriber::registry()
+            .with(fields.clone())
+            .set_default();
+
+        let stmt = cake::Entity::find().build(DbBackend::Postgres);
+        let mut stream = db.stream(stmt.clone()).await?;
+        stream.trace_rows(query_span(&stmt, None));
+        assert_eq!(stream.try_collect::<Vec<_>>().await?.len(), 2);
+
+        let fields = fields.0.lock().expect("Fail to acquire span fields");
+        assert!(fields.contains(&("otel.name".to_owned(), "SELECT cake".to_owned())));
+        assert_eq!(
+            fields.last(),
+            Some(&("db.rows_returned".to_owned(), "2".to_owned()))
+        );
+        Ok(())
+    }
+}